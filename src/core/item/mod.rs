@@ -3,28 +3,205 @@ pub mod state;
 
 pub use self::state::ItemState;
 
-use std::{slice, iter};
+use std::collections::HashSet;
+use std::ptr;
+use std::slice;
+use std::vec;
 use std::rc::Rc;
 
-use ::syntex_syntax::{ptr, ast};
-use ::itertools::Itertools;
+use ::syn;
+use ::syn::punctuated::Punctuated;
 
 use ::module::path::ModulePath;
+use ::module::scope::{Scope, item2ident};
+
+use self::state::abstraction::stub::Stub;
+use self::state::abstraction::utility::Utility;
+
+use super::model::Model;
 
 /// The structure Item is a iterable collection of abstract elements.
 
 #[derive(Debug, Clone)]
 pub struct Item <'a> {
+    /// List of all items, where the implementations are looked for.
+    list: &'a [(syn::Item, Rc<ModulePath>)],
     /// Iterator.
-    it: iter::Peekable<slice::Iter<'a, (ptr::P<ast::Item>, Rc<ModulePath>)>>,
+    it: slice::Iter<'a, (syn::Item, Rc<ModulePath>)>,
+    /// Definitions and imports, where the types' paths are resolved.
+    scope: Rc<Scope<'a>>,
+    /// Free functions, grouped by module.
+    functions: Rc<Vec<Vec<&'a (syn::Item, Rc<ModulePath>)>>>,
+    /// Names given to more than one type.
+    types: Rc<HashSet<String>>,
+    /// Names given to more than one utility of the free functions.
+    utilities: Rc<HashSet<String>>,
+    /// The crate's own containers and smart pointers.
+    model: Rc<Model>,
+    /// Extern types left from the last `extern` block.
+    opaque: vec::IntoIter<ItemState<'a>>,
+    /// Derived traits defined outside of the crate, given after the items.
+    stub: vec::IntoIter<ItemState<'a>>,
+}
+
+impl <'a> Item <'a> {
+
+    /// The method `is_unique` returns true when no other type, or else no other utility
+    /// of the free functions, has the name of the abstract element `state`.
+    pub fn is_unique(&self, state: &ItemState<'a>) -> bool {
+        state.as_name().map_or(false, |name| if state.is_utility() {
+            !self.utilities.contains(name)
+        } else {
+            !self.types.contains(name)
+        })
+    }
+
+    /// The method `is_implementation` returns true when the item `implem` is
+    /// an implementation of the type named `name` defined in the module `path`,
+    /// the implemented type being resolved from the implementation's module.
+    fn is_implementation(&self, name: &syn::Ident, path: &ModulePath, implem: &'a (syn::Item, Rc<ModulePath>)) -> bool {
+        if let &(syn::Item::Impl(syn::ItemImpl { ref self_ty, .. }), ref module) = implem {
+            ty2path(self_ty).and_then(|ty| self.scope.resolve_path(module, ty))
+                            .map_or(false, |ty| ty.eq(&path.join(name.to_string())))
+        } else {
+            false
+        }
+    }
+}
+
+/// The function `is_function` returns true when the item `item` is a free function, other than a test.
+fn is_function(item: &syn::Item) -> bool {
+    if let &syn::Item::Fn(syn::ItemFn { ref attrs, .. }) = item {
+        !attrs.iter().any(|attr| attr.path().is_ident("test"))
+    } else {
+        false
+    }
+}
+
+/// The function `functions` returns the free functions grouped by module.
+fn functions<'a>(list: &'a [(syn::Item, Rc<ModulePath>)]) -> Vec<Vec<&'a (syn::Item, Rc<ModulePath>)>> {
+    let mut functions: Vec<Vec<&'a (syn::Item, Rc<ModulePath>)>> = Vec::new();
+
+    for item in list.iter()
+                    .filter(|&&(ref item, _)| is_function(item)) {
+        match functions.iter_mut()
+                       .find(|functions| functions.first().map_or(false, |first| first.1.eq(&item.1))) {
+            Some(functions) => functions.push(item),
+            None => functions.push(vec!(item)),
+        }
+    }
+    functions
+}
+
+/// The function `utilities` returns the names given to more than one utility of the free functions.
+fn utilities(functions: &[Vec<&(syn::Item, Rc<ModulePath>)>]) -> HashSet<String> {
+    let mut names: HashSet<String> = HashSet::new();
+
+    functions.iter()
+             .filter_map(|functions| functions.first())
+             .map(|&&(_, ref path)| Utility::from(Rc::clone(path)).name)
+             .filter(|name| !names.insert(name.clone()))
+             .collect::<HashSet<String>>()
+}
+
+/// The function `ty2path` returns the path of the type targeted by an implementation
+/// or passed as an argument.
+pub fn ty2path(ty: &syn::Type) -> Option<&syn::Path> {
+    match ty {
+        &syn::Type::Path(syn::TypePath { qself: None, ref path }) => Some(path),
+        &syn::Type::TraitObject(syn::TypeTraitObject { ref bounds, .. }) => {
+            bounds.iter()
+                  .filter_map(|bound| if let &syn::TypeParamBound::Trait(syn::TraitBound { ref path, .. }) = bound {
+                      Some(path)
+                  } else {
+                      None
+                  })
+                  .next()
+        },
+        &syn::Type::Reference(syn::TypeReference { ref elem, .. }) |
+        &syn::Type::Paren(syn::TypeParen { ref elem, .. }) |
+        &syn::Type::Group(syn::TypeGroup { ref elem, .. }) => ty2path(elem),
+        _ => None,
+    }
+}
+
+/// The function `item2derives` returns the traits derived by a type's definition.
+pub fn item2derives(item: &syn::Item) -> Vec<syn::Path> {
+    match item {
+        &syn::Item::Struct(syn::ItemStruct { ref attrs, .. }) |
+        &syn::Item::Enum(syn::ItemEnum { ref attrs, .. }) |
+        &syn::Item::Union(syn::ItemUnion { ref attrs, .. }) => {
+            attrs.iter()
+                 .filter(|attr| attr.path().is_ident("derive"))
+                 .filter_map(|attr| attr.parse_args_with(Punctuated::<syn::Path, syn::Token![,]>::parse_terminated).ok())
+                 .flat_map(|derives| derives.into_iter())
+                 .collect::<Vec<syn::Path>>()
+        },
+        _ => Vec::new(),
+    }
+}
+
+/// The function `derive2path` returns the path of a derived trait defined outside of the crate,
+/// like it is written.
+pub fn derive2path(derive: &syn::Path) -> ModulePath {
+    ModulePath {
+        path: derive.segments.iter()
+                             .map(|segment| segment.ident.to_string().into())
+                             .collect(),
+    }
 }
 
-impl <'a>From<iter::Peekable<slice::Iter<'a, (ptr::P<ast::Item>, Rc<ModulePath>)>>> for Item<'a> {
+/// The function `stubs` returns the derived traits which aren't defined by the crate.
+fn stubs<'a>(list: &'a [(syn::Item, Rc<ModulePath>)], scope: &Scope<'a>) -> Vec<ItemState<'a>> {
+    let mut paths: Vec<ModulePath> = Vec::new();
+
+    for &(ref item, ref module) in list.iter() {
+        for derive in item2derives(item).iter()
+                                        .filter(|derive| scope.resolve_path(module, derive).is_none()) {
+            let path: ModulePath = derive2path(derive);
+
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+    }
+    paths.into_iter()
+         .filter_map(|mut path| path.path.pop().map(|name| (name.to_string_lossy().into_owned(), Rc::new(path))))
+         .map(|stub| ItemState::from(Stub::from(stub)))
+         .collect::<Vec<ItemState<'a>>>()
+}
+
+impl <'a>From<&'a [(syn::Item, Rc<ModulePath>)]> for Item<'a> {
 
     /// The constructor method `from` returns a typed and iterable collection of abstract element.
-    fn from(iter: iter::Peekable<slice::Iter<'a, (ptr::P<ast::Item>, Rc<ModulePath>)>>) -> Item {
+    fn from(list: &'a [(syn::Item, Rc<ModulePath>)]) -> Item<'a> {
+        Item::from((list, &Model::default()))
+    }
+}
+
+impl <'a, 'b>From<(&'a [(syn::Item, Rc<ModulePath>)], &'b Model)> for Item<'a> {
+
+    /// The constructor method `from` returns a typed and iterable collection of abstract element,
+    /// with the crate's own containers and smart pointers of the model `model`.
+    fn from((list, model): (&'a [(syn::Item, Rc<ModulePath>)], &'b Model)) -> Item<'a> {
+        let scope: Scope<'a> = Scope::from(list);
+        let functions: Vec<Vec<&'a (syn::Item, Rc<ModulePath>)>> = functions(list);
+        let stub: Vec<ItemState<'a>> = if cfg!(feature = "derive-realization") {
+            stubs(list, &scope)
+        } else {
+            Vec::new()
+        };
+
         Item {
-            it: iter,
+            list: list,
+            it: list.iter(),
+            types: Rc::new(scope.duplicates()),
+            utilities: Rc::new(utilities(&functions)),
+            functions: Rc::new(functions),
+            scope: Rc::new(scope),
+            model: Rc::new(model.clone()),
+            opaque: Vec::new().into_iter(),
+            stub: stub.into_iter(),
         }
     }
 }
@@ -33,20 +210,49 @@ impl <'a>Iterator for Item<'a> {
     type Item = ItemState<'a>;
 
     /// The method `next` will returns the first abstract elements defined like a structure,
-    /// enumeration or trait.
+    /// enumeration, union, alias, trait or extern type, with all its implementations,
+    /// or the utility of a module's free functions at the first of them,
+    /// and then the derived traits defined outside of the crate.
     fn next(&mut self) -> Option<ItemState<'a>> {
-        self.it.next().and_then(|item| {
-            let mut list: Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)> = vec!(item);
-
-            list.extend(self.it.peeking_take_while(|&&(ref item, _): (&&'a (ptr::P<ast::Item>, Rc<ModulePath>))| {
-                            if let ast::ItemKind::Impl(..) = item.node {
-                                true
-                            } else {    
-                                false
-                            }
-                        })
-                        .collect::<Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>>());
-            Some(ItemState::from(list))
+        if let Some(state) = self.opaque.next() {
+            return Some(state);
+        }
+        let item: Option<&'a (syn::Item, Rc<ModulePath>)> = self.it.next();
+
+        if item.is_none() {
+            return self.stub.next();
+        }
+        item.and_then(|item: &'a (syn::Item, Rc<ModulePath>)| {
+            if let &(syn::Item::ForeignMod(syn::ItemForeignMod { ref items, .. }), ref path) = item {
+                self.opaque = items.iter()
+                                   .filter_map(|foreign| if let &syn::ForeignItem::Type(ref opaque) = foreign {
+                                       let properties: Vec<&'a (syn::Item, Rc<ModulePath>)> = self.list.iter()
+                                                                                                .filter(|implem| self.is_implementation(&opaque.ident, path, implem))
+                                                                                                .collect::<Vec<&'a (syn::Item, Rc<ModulePath>)>>();
+
+                                       Some(ItemState::from(((opaque, Rc::clone(path)), properties, &*self.scope, &*self.model)))
+                                   } else {
+                                       None
+                                   })
+                                   .collect::<Vec<ItemState<'a>>>()
+                                   .into_iter();
+                Some(self.opaque.next().unwrap_or_default())
+            } else if let &(syn::Item::Fn(_), _) = item {
+                match self.functions.iter()
+                                    .find(|functions| functions.first().map_or(false, |first| ptr::eq(*first, item))) {
+                    Some(functions) => Some(ItemState::from((functions.clone(), &*self.scope, &*self.model))),
+                    None => Some(ItemState::default()),
+                }
+            } else {
+                let mut list: Vec<&'a (syn::Item, Rc<ModulePath>)> = vec!(item);
+
+                if let Some(name) = item2ident(&item.0) {
+                    list.extend(self.list.iter()
+                                         .filter(|implem| self.is_implementation(name, &item.1, implem))
+                                         .collect::<Vec<&'a (syn::Item, Rc<ModulePath>)>>());
+                }
+                Some(ItemState::from((list, &*self.scope, &*self.model)))
+            }
         })
     }
 }
@@ -2,13 +2,14 @@ use super::ItemState;
 
 use ::dot::{Fill, ArrowShape, Side};
 
-/// The enumeration `Relation` is the relationship specification from [UML 2.5](http://www.omg.org/spec/UML/2.5) without generalization.
+/// The enumeration `Relation` is the relationship specification from [UML 2.5](http://www.omg.org/spec/UML/2.5).
 #[derive(Debug, Copy, Clone)]
 pub enum Relation {
     Association,
     Aggregation,
     Composition,
     Realization,
+    Generalization,
     Dependency,
     None,
 }
@@ -23,9 +24,19 @@ impl Relation {
             &Relation::Aggregation => ArrowShape::Diamond(Fill::Open, Side::Both),
             &Relation::Composition => ArrowShape::Diamond(Fill::Filled, Side::Both),
             &Relation::Realization => ArrowShape::Normal(Fill::Open, Side::Both),
+            &Relation::Generalization => ArrowShape::Normal(Fill::Open, Side::Both),
             &Relation::None => ArrowShape::NoArrow,
         }
     }
+
+    /// The method `is_dashed` returns true when the relation is drawn by a dashed line,
+    /// like the dependencies and the realizations.
+    pub fn is_dashed(&self) -> bool {
+        match self {
+            &Relation::Dependency | &Relation::Realization => true,
+            _ => false,
+        }
+    }
 }
 
 impl <'a>From<(&'a ItemState<'a>, &'a ItemState<'a>)> for Relation {
@@ -34,12 +45,14 @@ impl <'a>From<(&'a ItemState<'a>, &'a ItemState<'a>)> for Relation {
             Relation::Composition
         } else if left.is_aggregation(right) {
             Relation::Aggregation
-        } else if left.is_dependency(right) {
-            Relation::Dependency
         } else if left.is_association(right) {
             Relation::Association
+        } else if left.is_dependency(right) {
+            Relation::Dependency
         } else if left.is_realization(right) {
             Relation::Realization
+        } else if left.is_generalization(right) {
+            Relation::Generalization
         } else {
             Relation::None
         }
@@ -0,0 +1,55 @@
+use std::fmt;
+use std::rc::Rc;
+
+use ::syn;
+
+use ::core::print::{ty_to_string, generic_param_to_string, where_predicate_to_string};
+
+use ::module::path::ModulePath;
+
+use super::template;
+
+use ::dot::escape_html;
+
+/// The structure `Alias` is a type alias abstract element.
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Alias<'a> {
+    pub path: Rc<ModulePath>,
+    /// Visibility
+    pub vis: &'a syn::Visibility,
+    pub name: String,
+    /// template's parameters.
+    pub params: Vec<String>,
+    /// `where` clause's predicates.
+    pub wheres: Vec<String>,
+    /// Aliased type.
+    pub ty: String,
+}
+
+impl <'a>From<(&'a syn::ItemType, Rc<ModulePath>)> for Alias<'a> {
+    fn from((item, path): (&'a syn::ItemType, Rc<ModulePath>)) -> Alias<'a> {
+        Alias {
+            path: path,
+            vis: &item.vis,
+            name: item.ident.to_string(),
+            params: item.generics.params
+                                 .iter()
+                                 .map(generic_param_to_string)
+                                 .collect::<Vec<String>>(),
+            wheres: item.generics.where_clause
+                                 .iter()
+                                 .flat_map(|clause| clause.predicates.iter().map(where_predicate_to_string))
+                                 .collect::<Vec<String>>(),
+            ty: ty_to_string(&item.ty),
+        }
+    }
+}
+
+impl <'a>fmt::Display for Alias<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "&lt;&lt;&lt;Alias&gt;&gt;&gt;\n{name}|{ty}",
+            name = template(&self.name, &self.params, &self.wheres),
+            ty = escape_html(self.ty.as_str()))
+    }
+}
@@ -1,11 +1,14 @@
 use std::fmt;
 use std::rc::Rc;
 
-use ::syntex_syntax::print::pprust::ty_to_string;
-use ::syntex_syntax::{codemap, symbol, ast};
+use ::syn;
+
+use ::core::print::{ty_to_string, generic_param_to_string, where_predicate_to_string};
 
 use ::module::path::ModulePath;
 
+use super::template;
+
 use ::dot::escape_html;
 
 /// The structure `Enum` is a enumerate abstract element.
@@ -14,33 +17,38 @@ use ::dot::escape_html;
 pub struct Enum<'a> {
     pub path: Rc<ModulePath>,
     /// Visibility
-    pub vis: &'a ast::Visibility,
-    pub name: symbol::InternedString,
-    pub params: Vec<symbol::InternedString>,
-    pub variants: Vec<(symbol::InternedString, Vec<String>)>,
+    pub vis: &'a syn::Visibility,
+    pub name: String,
+    /// template's parameters.
+    pub params: Vec<String>,
+    /// `where` clause's predicates.
+    pub wheres: Vec<String>,
+    /// variant's name, fields' names of the struct-like variants and types.
+    pub variants: Vec<(String, Vec<(Option<String>, String)>)>,
 }
 
-impl <'a>From<((&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::Variant>), Rc<ModulePath>)> for Enum<'a> {
-    fn from(((item, ty_params, variants), path): ((&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::Variant>), Rc<ModulePath>)) -> Enum<'a> {
+impl <'a>From<(&'a syn::ItemEnum, Rc<ModulePath>)> for Enum<'a> {
+    fn from((item, path): (&'a syn::ItemEnum, Rc<ModulePath>)) -> Enum<'a> {
         Enum {
             path: path,
             vis: &item.vis,
-            name: item.ident.name.as_str(),
-            params: ty_params.iter()
-                             .map(|&ast::TyParam {attrs: _, ident: ast::Ident {name, ..}, ..}| name.as_str())
-                             .collect::<Vec<symbol::InternedString>>(),
-            variants: variants.iter()
-                              .map(|&codemap::Spanned {node: ast::Variant_ {name: ast::Ident {name, ..}, attrs: _, ref data, ..}, ..}| {
-                                   if let &ast::VariantData::Tuple(ref struct_field, _) = data {
-                                       (name.as_str(),
-                                        struct_field.iter()
-                                                    .filter_map(|&ast::StructField { span: _, ident: _, vis: _, id: _, ref ty, .. }| Some(ty_to_string(&ty)))
-                                                    .collect::<Vec<String>>())
-                                   } else {
-                                       (name.as_str(), Vec::new())
-                                   }
-                              })
-                              .collect::<Vec<(symbol::InternedString, Vec<String>)>>(),
+            name: item.ident.to_string(),
+            params: item.generics.params
+                                 .iter()
+                                 .map(generic_param_to_string)
+                                 .collect::<Vec<String>>(),
+            wheres: item.generics.where_clause
+                                 .iter()
+                                 .flat_map(|clause| clause.predicates.iter().map(where_predicate_to_string))
+                                 .collect::<Vec<String>>(),
+            variants: item.variants.iter()
+                                   .map(|&syn::Variant {ref ident, ref fields, ..}|
+                                        (ident.to_string(),
+                                         fields.iter()
+                                               .map(|&syn::Field { ref ident, ref ty, .. }|
+                                                   (ident.as_ref().map(|ident| ident.to_string()), ty_to_string(&ty)))
+                                               .collect::<Vec<(Option<String>, String)>>()))
+                                   .collect::<Vec<(String, Vec<(Option<String>, String)>)>>(),
         }
     }
 }
@@ -48,16 +56,25 @@ impl <'a>From<((&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::Variant>), Rc
 impl <'a>fmt::Display for Enum<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.variants.is_empty() {
-            write!(f, "&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\n{name}", name = self.name)
+            write!(f, "&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\n{name}", name = template(&self.name, &self.params, &self.wheres))
         } else {
             write!(f, "&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\n{name}|{variants}",
-                name = self.name,
+                name = template(&self.name, &self.params, &self.wheres),
                 variants = escape_html(self.variants.iter()
-                                           .map(|&(ref name, ref struct_field): &(symbol::InternedString, Vec<String>)|
+                                           .map(|&(ref name, ref struct_field): &(String, Vec<(Option<String>, String)>)|
                                                 if struct_field.is_empty() {
                                                     format!("{}", name)
+                                                } else if struct_field.iter().all(|&(ref field, _)| field.is_none()) {
+                                                    format!("{}({})", name, struct_field.iter()
+                                                                                        .map(|&(_, ref ty)| ty.as_str())
+                                                                                        .collect::<Vec<&str>>()
+                                                                                        .join(", "))
                                                 } else {
-                                                    format!("{}({})", name, struct_field.join(", "))
+                                                    format!("{} \\{{ {} \\}}", name, struct_field.iter()
+                                                                                             .map(|&(ref field, ref ty)|
+                                                                                                 format!("{}: {}", field.as_ref().map_or("", String::as_str), ty))
+                                                                                             .collect::<Vec<String>>()
+                                                                                             .join(", "))
                                                 }
                                            )
                                            .collect::<Vec<String>>()
@@ -1,64 +1,130 @@
 use std::fmt;
-use std::ops::Deref;
 use std::rc::Rc;
 
-use ::syntex_syntax::print::pprust::ty_to_string;
-use ::syntex_syntax::{symbol, ast};
+use ::syn;
+
+use ::core::print::{ty_to_string, arg_to_ty, output_to_ty, expr_to_string, bound_to_string, generics_to_string, generic_param_to_string, where_predicate_to_string};
 
 use ::module::path::ModulePath;
 
+use super::template;
+use super::super::method::{Receiver, sig2qualifiers, qualify};
+
 use ::dot::escape_html;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Trait<'a> {
     pub path: Rc<ModulePath>,
     /// Visibility
-    pub vis: &'a ast::Visibility,
-    pub name: symbol::InternedString,
-    pub params: Vec<symbol::InternedString>,
-    pub items: Vec<(symbol::InternedString, Vec<String>, String)>,
+    pub vis: &'a syn::Visibility,
+    pub name: String,
+    /// template's parameters.
+    pub params: Vec<String>,
+    /// `where` clause's predicates.
+    pub wheres: Vec<String>,
+    /// associated type's name with its parameters, bounds, default.
+    pub types: Vec<(String, Vec<String>, Option<String>)>,
+    /// associated constant's name, type, default.
+    pub consts: Vec<(String, String, Option<String>)>,
+    /// method's name, arguments, result, provided by default, receiver, qualifiers.
+    pub items: Vec<(String, Vec<String>, Option<String>, bool, Receiver, Vec<&'static str>)>,
 }
 
-impl <'a>From<((&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::TraitItem>), Rc<ModulePath>)> for Trait<'a> {
-    fn from(((item, ty_params, trait_item), path): ((&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::TraitItem>), Rc<ModulePath>)) -> Trait<'a> {
+impl <'a>From<(&'a syn::ItemTrait, Rc<ModulePath>)> for Trait<'a> {
+    fn from((item, path): (&'a syn::ItemTrait, Rc<ModulePath>)) -> Trait<'a> {
         Trait {
             path: path,
             vis: &item.vis,
-            name: item.ident.name.as_str(),
-            params: ty_params.iter()
-                             .map(|&ast::TyParam {attrs: _, ident: ast::Ident {name, ..}, ..}| name.as_str())
-                             .collect::<Vec<symbol::InternedString>>(),
-            items: trait_item.iter()
-                             .filter_map(|&ast::TraitItem {id: _, ident: ast::Ident {name, ..}, attrs: _, ref node, ..}|
-                                   if let &ast::TraitItemKind::Method(ast::MethodSig { unsafety: _, constness: _, abi: _, ref decl, ..}, _) = node {
-                                       if let &ast::FnDecl {ref inputs, output: ast::FunctionRetTy::Ty(ref ty), ..} = decl.deref() {
-                                           Some((name.as_str(), inputs.iter().map(|input| ty_to_string(&input.ty)).collect::<Vec<String>>(), ty_to_string(&ty)))
-                                       } else {
-                                           None
-                                       }
+            name: item.ident.to_string(),
+            params: item.generics.params
+                                 .iter()
+                                 .map(generic_param_to_string)
+                                 .collect::<Vec<String>>(),
+            wheres: item.generics.where_clause
+                                 .iter()
+                                 .flat_map(|clause| clause.predicates.iter().map(where_predicate_to_string))
+                                 .collect::<Vec<String>>(),
+            types: item.items.iter()
+                             .filter_map(|trait_item: &'a syn::TraitItem|
+                                   if let &syn::TraitItem::Type(syn::TraitItemType { ref ident, ref generics, ref bounds, ref default, .. }) = trait_item {
+                                       Some((format!("{}{}", ident, generics_to_string(generics)),
+                                             bounds.iter().map(bound_to_string).collect::<Vec<String>>(),
+                                             default.as_ref().map(|&(_, ref ty)| ty_to_string(ty))))
                                    } else {
                                        None
                                    }
                              )
-                            .collect::<Vec<(symbol::InternedString, Vec<String>, String)>>()
+                             .collect::<Vec<(String, Vec<String>, Option<String>)>>(),
+            consts: item.items.iter()
+                              .filter_map(|trait_item: &'a syn::TraitItem|
+                                    if let &syn::TraitItem::Const(syn::TraitItemConst { ref ident, ref ty, ref default, .. }) = trait_item {
+                                        Some((ident.to_string(),
+                                              ty_to_string(ty),
+                                              default.as_ref().map(|&(_, ref expr)| expr_to_string(expr))))
+                                    } else {
+                                        None
+                                    }
+                              )
+                              .collect::<Vec<(String, String, Option<String>)>>(),
+            items: item.items.iter()
+                             .filter_map(|trait_item: &'a syn::TraitItem|
+                                   if let &syn::TraitItem::Fn(syn::TraitItemFn { ref sig, ref default, .. }) = trait_item {
+                                       let syn::Signature { ref ident, ref inputs, ref output, .. } = *sig;
+
+                                       Some((ident.to_string(), inputs.iter().map(|input| ty_to_string(arg_to_ty(input))).collect::<Vec<String>>(), output_to_ty(output).map(ty_to_string), default.is_some(), Receiver::from(sig), sig2qualifiers(sig)))
+                                   } else {
+                                       None
+                                   }
+                             )
+                            .collect::<Vec<(String, Vec<String>, Option<String>, bool, Receiver, Vec<&'static str>)>>()
         }
     }
 }
 
 impl <'a>fmt::Display for Trait<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "&lt;&lt;&lt;Trait&gt;&gt;&gt;\n{name}|{items}",
-           name = self.name,
-           items = escape_html(self.items.iter()
-                                   .map(|&(ref name, ref ty, ref ret): &(symbol::InternedString, Vec<String>, String)|
-                                        format!("{name}({ty}) -> {ret}",
-                                            name = name,
-                                            ty = ty.join(", "),
-                                            ret = ret
-                                        ))
-                                   .collect::<Vec<String>>()
-                                   .join("\n")
-                                   .as_str())
+        write!(f, "&lt;&lt;&lt;Trait&gt;&gt;&gt;\n{name}", name = template(&self.name, &self.params, &self.wheres))?;
+        if !self.types.is_empty() {
+            write!(f, "|{types}",
+               types = escape_html(self.types.iter()
+                                       .map(|&(ref name, ref bounds, ref default): &(String, Vec<String>, Option<String>)|
+                                            format!("type {name}{bounds}{default}",
+                                                name = name,
+                                                bounds = if bounds.is_empty() { String::new() } else { format!(": {}", bounds.join(" + ")) },
+                                                default = default.as_ref().map(|ty| format!(" = {}", ty)).unwrap_or_default()
+                                            ))
+                                       .collect::<Vec<String>>()
+                                       .join("\n")
+                                       .as_str())
+            )?;
+        }
+        if !self.consts.is_empty() {
+            write!(f, "|{consts}",
+               consts = escape_html(self.consts.iter()
+                                        .map(|&(ref name, ref ty, ref default): &(String, String, Option<String>)|
+                                             format!("const {name}: {ty}{default}",
+                                                 name = name,
+                                                 ty = ty,
+                                                 default = default.as_ref().map(|expr| format!(" = {}", expr)).unwrap_or_default()
+                                             ))
+                                        .collect::<Vec<String>>()
+                                        .join("\n")
+                                        .as_str())
+            )?;
+        }
+        write!(f, "|{items}",
+           items = self.items.iter()
+                             .map(|&(ref name, ref ty, ref ret, provided, receiver, ref qualifiers): &(String, Vec<String>, Option<String>, bool, Receiver, Vec<&'static str>)|
+                                  format!("{func}{abstraction}",
+                                      func = qualify(&format!("{name}({ty}){ret}",
+                                                         name = name,
+                                                         ty = ty.join(", "),
+                                                         ret = ret.as_ref().map(|ret| format!(" -> {}", ret)).unwrap_or_default()),
+                                                     receiver, qualifiers),
+                                      abstraction = if provided { "" } else { " \\{abstract\\}" }
+                                  ))
+                             .collect::<Vec<String>>()
+                             .join("\n")
         )
     }
 }
@@ -1,19 +1,44 @@
 pub mod extend;
 pub mod structure;
 pub mod enumerate;
+pub mod union;
+pub mod alias;
+pub mod opaque;
+pub mod stub;
+pub mod utility;
 
 use std::fmt;
 use std::vec;
 use std::rc::Rc;
 
-use ::syntex_syntax::symbol;
-use ::core::ast;
+use ::syn;
 
 use ::module::path::ModulePath;
 
+use ::dot::escape_html;
+
 use self::extend::Trait;
 use self::structure::Struct;
 use self::enumerate::Enum;
+use self::union::Union;
+use self::alias::Alias;
+use self::opaque::Opaque;
+use self::stub::Stub;
+use self::utility::Utility;
+
+/// The function `template` returns the name of a classifier followed by its template's parameters
+/// and its `where` clause.
+pub fn template(name: &str, params: &[String], wheres: &[String]) -> String {
+    let mut template: String = String::from(name);
+
+    if !params.is_empty() {
+        template.push_str(&format!("<{}>", params.join(", ")));
+    }
+    if !wheres.is_empty() {
+        template.push_str(&format!("\nwhere {}", wheres.join(", ")));
+    }
+    escape_html(&template)
+}
 
 /// The structure `Abstract` is a enumerate for abstract element types or none.
 
@@ -22,6 +47,11 @@ pub enum Abstract <'a> {
     Trait(Trait<'a>),
     Struct(Struct<'a>),
     Enum(Enum<'a>),
+    Union(Union<'a>),
+    Alias(Alias<'a>),
+    Opaque(Opaque<'a>),
+    Stub(Stub),
+    Utility(Utility),
     None,
 }
 
@@ -29,11 +59,32 @@ impl <'a> Abstract <'a> {
 
     /// The method `as_name` returns the name of the abstract element
     /// or else declare a panic.
-    pub fn as_name(&self) -> Option<&symbol::InternedString> {
+    pub fn as_name(&self) -> Option<&String> {
         match self {
             &Abstract::Trait(Trait { vis: _, ref name, ..}) => Some(name),
             &Abstract::Struct(Struct { vis: _, ref name, ..}) => Some(name),
             &Abstract::Enum(Enum { vis: _, ref name, ..}) => Some(name),
+            &Abstract::Union(Union { vis: _, ref name, ..}) => Some(name),
+            &Abstract::Alias(Alias { vis: _, ref name, ..}) => Some(name),
+            &Abstract::Opaque(Opaque { vis: _, ref name, ..}) => Some(name),
+            &Abstract::Stub(Stub { ref name, ..}) => Some(name),
+            &Abstract::Utility(Utility { ref name, ..}) => Some(name),
+            &Abstract::None => None,
+        }
+    }
+
+    /// The method `as_path` returns the module's path of the abstract element
+    /// followed by its name.
+    pub fn as_path(&self) -> Option<ModulePath> {
+        match self {
+            &Abstract::Trait(Trait { ref path, ref name, ..}) => Some(path.join(name)),
+            &Abstract::Struct(Struct { ref path, ref name, ..}) => Some(path.join(name)),
+            &Abstract::Enum(Enum { ref path, ref name, ..}) => Some(path.join(name)),
+            &Abstract::Union(Union { ref path, ref name, ..}) => Some(path.join(name)),
+            &Abstract::Alias(Alias { ref path, ref name, ..}) => Some(path.join(name)),
+            &Abstract::Opaque(Opaque { ref path, ref name, ..}) => Some(path.join(name)),
+            &Abstract::Stub(Stub { ref path, ref name, ..}) => Some(path.join(name)),
+            &Abstract::Utility(Utility { ref path, ref name, ..}) => Some(path.join(name)),
             &Abstract::None => None,
         }
     }
@@ -45,22 +96,26 @@ impl<'a> IntoIterator for &'a Abstract<'a> {
 
     fn into_iter(self) -> Self::IntoIter {
         match self {
-            &Abstract::Struct(Struct {path: _, vis: _, name: _, fields: ref ty_field}) => {
+            &Abstract::Struct(Struct {path: _, vis: _, name: _, params: _, wheres: _, fields: ref ty_field}) |
+            &Abstract::Union(Union {path: _, vis: _, name: _, params: _, wheres: _, fields: ref ty_field}) => {
                 ty_field.iter()
-                        .map(|&(_, _, ref ty): &'a (&'a ast::Visibility, symbol::InternedString, String)| ty)
+                        .map(|&(_, _, ref ty): &'a (&'a syn::Visibility, String, String)| ty)
                         .collect::<Vec<&'a String>>()
                         .into_iter()
             },
-            &Abstract::Enum(Enum {path: _, vis: _, name: _, params: _, variants: ref ty_multi_field}) => {
+            &Abstract::Enum(Enum {path: _, vis: _, name: _, params: _, wheres: _, variants: ref ty_multi_field}) => {
                 ty_multi_field.iter()
-                              .map(|&(_, ref ty_field): &'a (symbol::InternedString, Vec<String>)| 
+                              .map(|&(_, ref ty_field): &'a (String, Vec<(Option<String>, String)>)|
                                    ty_field.iter()
-                                           .map(|ty: &'a String| ty)
+                                           .map(|&(_, ref ty): &'a (Option<String>, String)| ty)
                                            .collect::<Vec<&'a String>>())
                               .collect::<Vec<Vec<&'a String>>>()
                               .concat()
                               .into_iter()
             },
+            &Abstract::Alias(Alias {path: _, vis: _, name: _, params: _, wheres: _, ref ty}) => {
+                vec!(ty).into_iter()
+            },
             _ => {
                 Vec::default().into_iter()
             },
@@ -74,30 +129,65 @@ impl <'a> Default for Abstract<'a> {
     }
 }
 
-impl <'a>From<((&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::TraitItem>), Rc<ModulePath>)> for Abstract<'a> {
-    fn from(arguments: ((&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::TraitItem>), Rc<ModulePath>)) -> Abstract<'a> {
+impl <'a>From<(&'a syn::ItemTrait, Rc<ModulePath>)> for Abstract<'a> {
+    fn from(arguments: (&'a syn::ItemTrait, Rc<ModulePath>)) -> Abstract<'a> {
         Abstract::Trait(Trait::from(arguments))
     }
 }
 
-impl <'a>From<((&'a ast::Item, &'a Vec<ast::StructField>), Rc<ModulePath>)> for Abstract<'a> {
-    fn from(arguments: ((&'a ast::Item, &'a Vec<ast::StructField>), Rc<ModulePath>)) -> Abstract<'a> {
+impl <'a>From<(&'a syn::ItemStruct, Rc<ModulePath>)> for Abstract<'a> {
+    fn from(arguments: (&'a syn::ItemStruct, Rc<ModulePath>)) -> Abstract<'a> {
         Abstract::Struct(Struct::from(arguments))
     }
 }
 
-impl <'a>From<((&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::Variant>), Rc<ModulePath>)> for Abstract<'a> {
-    fn from(arguments: ((&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::Variant>), Rc<ModulePath>)) -> Abstract<'a> {
+impl <'a>From<(&'a syn::ItemEnum, Rc<ModulePath>)> for Abstract<'a> {
+    fn from(arguments: (&'a syn::ItemEnum, Rc<ModulePath>)) -> Abstract<'a> {
         Abstract::Enum(Enum::from(arguments))
     }
 }
 
+impl <'a>From<(&'a syn::ItemUnion, Rc<ModulePath>)> for Abstract<'a> {
+    fn from(arguments: (&'a syn::ItemUnion, Rc<ModulePath>)) -> Abstract<'a> {
+        Abstract::Union(Union::from(arguments))
+    }
+}
+
+impl <'a>From<(&'a syn::ItemType, Rc<ModulePath>)> for Abstract<'a> {
+    fn from(arguments: (&'a syn::ItemType, Rc<ModulePath>)) -> Abstract<'a> {
+        Abstract::Alias(Alias::from(arguments))
+    }
+}
+
+impl <'a>From<(&'a syn::ForeignItemType, Rc<ModulePath>)> for Abstract<'a> {
+    fn from(arguments: (&'a syn::ForeignItemType, Rc<ModulePath>)) -> Abstract<'a> {
+        Abstract::Opaque(Opaque::from(arguments))
+    }
+}
+
+impl <'a>From<Stub> for Abstract<'a> {
+    fn from(stub: Stub) -> Abstract<'a> {
+        Abstract::Stub(stub)
+    }
+}
+
+impl <'a>From<Rc<ModulePath>> for Abstract<'a> {
+    fn from(module: Rc<ModulePath>) -> Abstract<'a> {
+        Abstract::Utility(Utility::from(module))
+    }
+}
+
 impl <'a>fmt::Display for Abstract<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             &Abstract::Struct(ref item) => write!(f, "{}", item),
             &Abstract::Enum(ref item) => write!(f, "{}", item),
             &Abstract::Trait(ref item) => write!(f, "{}", item),
+            &Abstract::Union(ref item) => write!(f, "{}", item),
+            &Abstract::Alias(ref item) => write!(f, "{}", item),
+            &Abstract::Opaque(ref item) => write!(f, "{}", item),
+            &Abstract::Stub(ref item) => write!(f, "{}", item),
+            &Abstract::Utility(ref item) => write!(f, "{}", item),
             &Abstract::None => Err(fmt::Error),
         }
     }
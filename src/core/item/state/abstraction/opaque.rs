@@ -0,0 +1,32 @@
+use std::fmt;
+use std::rc::Rc;
+
+use ::syn;
+
+use ::module::path::ModulePath;
+
+/// The structure `Opaque` is an extern type abstract element, whose layout is unknown.
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Opaque<'a> {
+    pub path: Rc<ModulePath>,
+    /// Visibility
+    pub vis: &'a syn::Visibility,
+    pub name: String,
+}
+
+impl <'a>From<(&'a syn::ForeignItemType, Rc<ModulePath>)> for Opaque<'a> {
+    fn from((item, path): (&'a syn::ForeignItemType, Rc<ModulePath>)) -> Opaque<'a> {
+        Opaque {
+            path: path,
+            vis: &item.vis,
+            name: item.ident.to_string(),
+        }
+    }
+}
+
+impl <'a>fmt::Display for Opaque<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "&lt;&lt;&lt;Opaque&gt;&gt;&gt;\n{name}", name = self.name)
+    }
+}
@@ -1,11 +1,14 @@
 use std::fmt;
 use std::rc::Rc;
 
-use ::syntex_syntax::print::pprust::ty_to_string;
-use ::syntex_syntax::{symbol, ast};
+use ::syn;
+
+use ::core::print::{ty_to_string, vis_to_marker, generic_param_to_string, where_predicate_to_string};
 
 use ::module::path::ModulePath;
 
+use super::template;
+
 use ::dot::escape_html;
 
 /// The structure `Struct` is a structure abstract element.
@@ -14,21 +17,34 @@ use ::dot::escape_html;
 pub struct Struct<'a> {
     pub path: Rc<ModulePath>,
     /// Visibility
-    pub vis: &'a ast::Visibility,
-    pub name: symbol::InternedString,
-    pub fields: Vec<(&'a ast::Visibility, symbol::InternedString, String)>,
+    pub vis: &'a syn::Visibility,
+    pub name: String,
+    /// template's parameters.
+    pub params: Vec<String>,
+    /// `where` clause's predicates.
+    pub wheres: Vec<String>,
+    pub fields: Vec<(&'a syn::Visibility, String, String)>,
 }
 
-impl <'a>From<((&'a ast::Item, &'a Vec<ast::StructField>), Rc<ModulePath>)> for Struct<'a> {
-    fn from(((item, struct_field), path): ((&'a ast::Item, &'a Vec<ast::StructField>), Rc<ModulePath>)) -> Struct<'a> {
+impl <'a>From<(&'a syn::ItemStruct, Rc<ModulePath>)> for Struct<'a> {
+    fn from((item, path): (&'a syn::ItemStruct, Rc<ModulePath>)) -> Struct<'a> {
         Struct {
             path: path,
             vis: &item.vis,
-            name: item.ident.name.as_str(),
-            fields: struct_field.iter()
-                                .filter_map(|&ast::StructField { span: _, ident, ref vis, id: _, ref ty, .. }|
-                                           ident.and_then(|ast::Ident {name, ..}| Some((vis, name.as_str(), ty_to_string(&ty)))))
-                                .collect::<Vec<(&ast::Visibility, symbol::InternedString, String)>>()
+            name: item.ident.to_string(),
+            params: item.generics.params
+                                 .iter()
+                                 .map(generic_param_to_string)
+                                 .collect::<Vec<String>>(),
+            wheres: item.generics.where_clause
+                                 .iter()
+                                 .flat_map(|clause| clause.predicates.iter().map(where_predicate_to_string))
+                                 .collect::<Vec<String>>(),
+            fields: item.fields.iter()
+                               .enumerate()
+                               .map(|(index, &syn::Field { ref ident, ref vis, ref ty, .. })|
+                                   (vis, ident.as_ref().map_or(index.to_string(), |ident| ident.to_string()), ty_to_string(&ty)))
+                               .collect::<Vec<(&syn::Visibility, String, String)>>()
         }
     }
 }
@@ -36,17 +52,13 @@ impl <'a>From<((&'a ast::Item, &'a Vec<ast::StructField>), Rc<ModulePath>)> for
 impl <'a>fmt::Display for Struct<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.fields.is_empty() {
-            write!(f, "&lt;&lt;&lt;Structure&gt;&gt;&gt;\n{name}", name = self.name)
+            write!(f, "&lt;&lt;&lt;Structure&gt;&gt;&gt;\n{name}", name = template(&self.name, &self.params, &self.wheres))
         } else {
             write!(f, "&lt;&lt;&lt;Structure&gt;&gt;&gt;\n{name}|{fields}",
-                name = self.name,
+                name = template(&self.name, &self.params, &self.wheres),
                 fields = escape_html(self.fields.iter()
-                                                .map(|&(ref vis, ref name, ref ty): &(&ast::Visibility, symbol::InternedString, String)|
-                                                    if ast::Visibility::Public.eq(vis) {
-                                                        format!("+ {name}: {ty}", name = name, ty = ty)
-                                                    } else {
-                                                        format!("- {name}: {ty}", name = name, ty = ty)
-                                                    }
+                                                .map(|&(ref vis, ref name, ref ty): &(&syn::Visibility, String, String)|
+                                                    format!("{vis} {name}: {ty}", vis = vis_to_marker(vis), name = name, ty = ty)
                                                 )
                                                 .collect::<Vec<String>>()
                                                 .join("\n")
@@ -0,0 +1,28 @@
+use std::fmt;
+use std::rc::Rc;
+
+use ::module::path::ModulePath;
+
+/// The structure `Stub` is a trait abstract element defined outside of the crate,
+/// known only by the types which derive it.
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Stub {
+    pub path: Rc<ModulePath>,
+    pub name: String,
+}
+
+impl From<(String, Rc<ModulePath>)> for Stub {
+    fn from((name, path): (String, Rc<ModulePath>)) -> Stub {
+        Stub {
+            path: path,
+            name: name,
+        }
+    }
+}
+
+impl fmt::Display for Stub {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "&lt;&lt;&lt;Trait&gt;&gt;&gt;\n{name}", name = self.name)
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+use std::rc::Rc;
+
+use ::syn;
+
+use ::core::print::{ty_to_string, vis_to_marker, generic_param_to_string, where_predicate_to_string};
+
+use ::module::path::ModulePath;
+
+use super::template;
+
+use ::dot::escape_html;
+
+/// The structure `Union` is a union abstract element.
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Union<'a> {
+    pub path: Rc<ModulePath>,
+    /// Visibility
+    pub vis: &'a syn::Visibility,
+    pub name: String,
+    /// template's parameters.
+    pub params: Vec<String>,
+    /// `where` clause's predicates.
+    pub wheres: Vec<String>,
+    pub fields: Vec<(&'a syn::Visibility, String, String)>,
+}
+
+impl <'a>From<(&'a syn::ItemUnion, Rc<ModulePath>)> for Union<'a> {
+    fn from((item, path): (&'a syn::ItemUnion, Rc<ModulePath>)) -> Union<'a> {
+        Union {
+            path: path,
+            vis: &item.vis,
+            name: item.ident.to_string(),
+            params: item.generics.params
+                                 .iter()
+                                 .map(generic_param_to_string)
+                                 .collect::<Vec<String>>(),
+            wheres: item.generics.where_clause
+                                 .iter()
+                                 .flat_map(|clause| clause.predicates.iter().map(where_predicate_to_string))
+                                 .collect::<Vec<String>>(),
+            fields: item.fields.named
+                               .iter()
+                               .filter_map(|&syn::Field { ref ident, ref vis, ref ty, .. }|
+                                   ident.as_ref().map(|ident| (vis, ident.to_string(), ty_to_string(&ty))))
+                               .collect::<Vec<(&syn::Visibility, String, String)>>()
+        }
+    }
+}
+
+impl <'a>fmt::Display for Union<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.fields.is_empty() {
+            write!(f, "&lt;&lt;&lt;Union&gt;&gt;&gt;\n{name}", name = template(&self.name, &self.params, &self.wheres))
+        } else {
+            write!(f, "&lt;&lt;&lt;Union&gt;&gt;&gt;\n{name}|{fields}",
+                name = template(&self.name, &self.params, &self.wheres),
+                fields = escape_html(self.fields.iter()
+                                                .map(|&(ref vis, ref name, ref ty): &(&syn::Visibility, String, String)|
+                                                    format!("{vis} {name}: {ty}", vis = vis_to_marker(vis), name = name, ty = ty)
+                                                )
+                                                .collect::<Vec<String>>()
+                                                .join("\n")
+                                                .as_str()),
+            )
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+use std::rc::Rc;
+
+use ::module::path::ModulePath;
+
+/// The name of the utility of the crate's root module.
+const DEFAULT_NAME_ROOT: &'static str = "crate";
+
+/// The structure `Utility` is a module abstract element holding the free functions,
+/// like the utility classes from [UML 2.5](http://www.omg.org/spec/UML/2.5).
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Utility {
+    pub path: Rc<ModulePath>,
+    pub name: String,
+}
+
+impl From<Rc<ModulePath>> for Utility {
+
+    /// The constructor method `from` returns the utility of the module `module`,
+    /// named like the module without its raw prefix `r#`, or else `crate` for the crate's root.
+    fn from(module: Rc<ModulePath>) -> Utility {
+        let mut path: ModulePath = (*module).clone();
+        let name: String = path.path.pop()
+                                    .map(|name| name.to_string_lossy().trim_start_matches("r#").to_string())
+                                    .unwrap_or_else(|| String::from(DEFAULT_NAME_ROOT));
+
+        Utility {
+            path: Rc::new(path),
+            name: name,
+        }
+    }
+}
+
+impl fmt::Display for Utility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "&lt;&lt;&lt;Utility&gt;&gt;&gt;\n{name}", name = self.name)
+    }
+}
@@ -1,107 +1,90 @@
 use super::DEFAULT_FUNC;
 
-use std::ops::Deref;
 use std::fmt;
 
-use ::syntex_syntax::print::pprust::ty_to_string;
-use ::syntex_syntax::symbol::InternedString;
-use ::syntex_syntax::ast;
+use ::syn;
+use ::syn::punctuated::Punctuated;
 
-use ::dot::escape_html;
+use ::core::print::{ty_to_string, arg_to_ty, output_to_ty, vis_to_marker};
+
+use super::method::{Receiver, sig2qualifiers, qualify};
 
 /// The structure `Implem` is a collection of methods and tyes for an abstract element.
 
 #[derive(Default, Debug, Clone, Eq, PartialEq)]
 pub struct Implem {
-    ty: Vec<(InternedString, Vec<String>)>,
-    /// method's name, arguments, result.
-    method: Vec<(InternedString, Vec<String>, Option<String>)>,
+    /// Visibility of the trait, or else none when the trait is defined outside of the crate.
+    vis: Option<syn::Visibility>,
+    ty: Vec<(String, Vec<String>)>,
+    /// method's name, arguments, result, redefines a provided method of the trait, receiver, qualifiers.
+    method: Vec<(String, Vec<String>, Option<String>, bool, Receiver, Vec<&'static str>)>,
 }
 
-impl Implem {
-    pub fn is_realization(&self, ty_name: &String) -> bool {
-        if let Some(&(ref name, _)) = self.ty.first() {
-            name.to_string().eq(ty_name)
-        } else {
-            false
-        }
-    }
-
-    pub fn is_association(&self, ty_name: &String) -> bool {
-        self.method.iter()
-                   .any(|&(_, _, ref result): &(InternedString, Vec<String>, Option<String>)|
-                       if let &Some(ref ret) = result {
-                           ret.split(|at| "<[(;, )]>".contains(at))
-                              .any(|ty| ty.eq(ty_name))
-                       } else {
-                           false
-                       }
-                   )
-    }
-
-    pub fn is_dependency(&self, _: &String) -> bool {
-        false
-        /*self.method.iter()
-                   .any(|&( _, ref arg, _): &(InternedString, Vec<String>, Option<String>)|
-                       arg.iter().any(|ty| ty.ends_with(name)))*/
-    }
-}
-
-impl From<(Vec<(InternedString, Vec<String>)>, Vec<(InternedString, Vec<String>, Option<String>)>)> for Implem {
-    fn from((ty, method): (Vec<(InternedString, Vec<String>)>, Vec<(InternedString, Vec<String>, Option<String>)>)) -> Implem {
+impl From<(Option<syn::Visibility>, Vec<(String, Vec<String>)>, Vec<(String, Vec<String>, Option<String>, bool, Receiver, Vec<&'static str>)>)> for Implem {
+    fn from((vis, ty, method): (Option<syn::Visibility>, Vec<(String, Vec<String>)>, Vec<(String, Vec<String>, Option<String>, bool, Receiver, Vec<&'static str>)>)) -> Implem {
         Implem {
+            vis: vis,
             ty: ty,
             method: method,
         }
     }
 }
 
-impl <'a> From<(&'a Vec<ast::PathSegment>, &'a Vec<ast::ImplItem>)> for Implem {
-    fn from((segments, impl_item): (&'a Vec<ast::PathSegment>, &'a Vec<ast::ImplItem>)) -> Implem {
-        Implem::from((segments.iter()
-                              .map(|&ast::PathSegment { identifier: ast::Ident {name, ..}, span: _, ref parameters }| {
-                                  if let &Some(ref path) = parameters {
-                                      if let &ast::PathParameters::AngleBracketed(
-                                          ast::AngleBracketedParameterData { lifetimes: _, ref types, .. }
-                                      ) = path.deref() {
-                                          (name.as_str(), types.iter().map(|ty| ty_to_string(&ty)).collect::<Vec<String>>())
-                                      } else {
-                                          (name.as_str(), Vec::new())
-                                      }
+impl <'a> From<(&'a Punctuated<syn::PathSegment, syn::token::PathSep>, &'a Vec<syn::ImplItem>, Option<&'a syn::ItemTrait>)> for Implem {
+    fn from((segments, impl_item, trait_): (&'a Punctuated<syn::PathSegment, syn::token::PathSep>, &'a Vec<syn::ImplItem>, Option<&'a syn::ItemTrait>)) -> Implem {
+        let provided: Vec<&'a syn::Ident> = trait_.map_or(Vec::new(), |trait_|
+            trait_.items.iter()
+                        .filter_map(|trait_item| match trait_item {
+                            &syn::TraitItem::Fn(syn::TraitItemFn { ref sig, default: Some(_), .. }) => Some(&sig.ident),
+                            _ => None,
+                        })
+                        .collect::<Vec<&'a syn::Ident>>());
+
+
+        Implem::from((trait_.map(|trait_| trait_.vis.clone()),
+                      segments.iter()
+                              .map(|&syn::PathSegment { ref ident, ref arguments }| {
+                                  if let &syn::PathArguments::AngleBracketed(
+                                      syn::AngleBracketedGenericArguments { ref args, .. }
+                                  ) = arguments {
+                                      (ident.to_string(), args.iter()
+                                                              .filter_map(|arg| if let &syn::GenericArgument::Type(ref ty) = arg {
+                                                                  Some(ty_to_string(&ty))
+                                                              } else {
+                                                                  None
+                                                              })
+                                                              .collect::<Vec<String>>())
                                   } else {
-                                      (name.as_str(), Vec::new())
+                                      (ident.to_string(), Vec::new())
                                   }
                               })
-                              .collect::<Vec<(InternedString, Vec<String>)>>(),
+                              .collect::<Vec<(String, Vec<String>)>>(),
                       impl_item.iter()
-                               .filter_map(|&ast::ImplItem { id: _, ident: ast::Ident {name, ..}, vis: _, defaultness: _, attrs: _, ref node, ..}|
-                                         if let &ast::ImplItemKind::Method(ast::MethodSig { unsafety: _, constness: _, abi: _, ref decl, .. }, _) = node {
-                                             if let ast::FunctionRetTy::Ty(ref ty) = decl.output {
-                                                 Some((name.as_str(), decl.inputs.iter().map(|arg| ty_to_string(&arg.ty)).collect::<Vec<String>>(), Some(ty_to_string(&ty))))
-                                             } else {
-                                                 Some((name.as_str(), decl.inputs.iter().map(|arg| ty_to_string(&arg.ty)).collect::<Vec<String>>(), None))
-                                             }
+                               .filter_map(|impl_item: &'a syn::ImplItem|
+                                         if let &syn::ImplItem::Fn(syn::ImplItemFn { ref sig, .. }) = impl_item {
+                                             let syn::Signature { ref ident, ref inputs, ref output, .. } = *sig;
+
+                                             Some((ident.to_string(), inputs.iter().map(|arg| ty_to_string(arg_to_ty(arg))).collect::<Vec<String>>(), output_to_ty(output).map(ty_to_string), provided.contains(&ident), Receiver::from(sig), sig2qualifiers(sig)))
                                          } else {
                                              None
                                          }
-                               ).collect::<Vec<(InternedString, Vec<String>, Option<String>)>>()))
+                               ).collect::<Vec<(String, Vec<String>, Option<String>, bool, Receiver, Vec<&'static str>)>>()))
     }
 }
 
 impl fmt::Display for Implem {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{item}",
-           item = escape_html(self.method.iter()
-                                         .map(|&(ref name, ref args, ref result): &(InternedString, Vec<String>, Option<String>)| {
-                                             if let &Some(ref ret) = result {
-                                                 format!("{}{}({}) -> {}", DEFAULT_FUNC, name, args.join(", "), ret)
-                                             } else {
-                                                 format!("{}{}({})", DEFAULT_FUNC, name, args.join(", "))
-                                             }
-                                         })
-                                         .collect::<Vec<String>>()
-                                         .join("\n")
-                                         .as_str()))
+           item = self.method.iter()
+                             .map(|&(ref name, ref args, ref result, redefine, receiver, ref qualifiers): &(String, Vec<String>, Option<String>, bool, Receiver, Vec<&'static str>)| {
+                                 format!("{}{}",
+                                     qualify(&format!("{}{}{}({}){}", self.vis.as_ref().map_or("+", vis_to_marker), DEFAULT_FUNC, name, args.join(", "),
+                                                 result.as_ref().map(|ret| format!(" -> {}", ret)).unwrap_or_default()),
+                                             receiver, qualifiers),
+                                     if redefine { " \\{redefines\\}" } else { "" })
+                             })
+                             .collect::<Vec<String>>()
+                             .join("\n"))
         /*if let Some(&(ref name, ref template)) = self.ty.last() {
             if template.is_empty() {
                 write!(f, "{name}", name = name.to_string())
@@ -1,48 +1,106 @@
 use super::DEFAULT_FUNC;
 
-use std::ops::Deref;
 use std::fmt;
 use std::rc::Rc;
 
-use ::syntex_syntax::print::pprust::{ty_to_string, arg_to_string};
-use ::syntex_syntax::symbol::InternedString;
-use ::syntex_syntax::ast;
+use ::syn;
+
+use ::core::print::{ty_to_string, arg_to_string, output_to_ty, vis_to_marker};
 
 use ::module::path::ModulePath;
 
 use ::dot::escape_html;
 
-/// The structure `Method` is a collection of methods from a abstract element.
+/// The enumeration `Receiver` is the kind of `self` taken by a method.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Receiver {
+    /// `self` or typed like `self: Box<Self>`.
+    Value,
+    /// `&self`.
+    Ref,
+    /// `&mut self`.
+    RefMut,
+    /// Associated function without `self`.
+    None,
+}
 
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
-pub struct Method <'a> {
-    /// visibility, method's name, arguments, result.
-    func: Vec<(&'a ast::Visibility, InternedString, Vec<String>, Option<String>)>,
-    path: Rc<ModulePath>,
+impl <'a> From<&'a syn::Signature> for Receiver {
+    fn from(sig: &'a syn::Signature) -> Receiver {
+        match sig.receiver() {
+            Some(&syn::Receiver { ref ty, .. }) => match &**ty {
+                &syn::Type::Reference(syn::TypeReference { mutability: Some(_), .. }) => Receiver::RefMut,
+                &syn::Type::Reference(syn::TypeReference { mutability: None, .. }) => Receiver::Ref,
+                _ => Receiver::Value,
+            },
+            None => Receiver::None,
+        }
+    }
 }
 
-impl <'a> Method <'a> {
-    pub fn is_association(&self, ty_name: &String) -> bool {
-        self.func.iter()
-                 .any(|&(_, _, _, ref result): &(&'a ast::Visibility, InternedString, Vec<String>, Option<String>)|
-                     if let &Some(ref ret) = result {
-                         ret.split(|at| "<[(;, )]>".contains(at))
-                            .any(|ty| ty.eq(ty_name))
-                     } else {
-                         false
-                     }
-                 )
+/// The function `sig2qualifiers` returns the qualifiers `const`, `async` and `unsafe` of a signature.
+pub fn sig2qualifiers(sig: &syn::Signature) -> Vec<&'static str> {
+    vec!((sig.constness.is_some(), "const"),
+         (sig.asyncness.is_some(), "async"),
+         (sig.unsafety.is_some(), "unsafe")).into_iter()
+                                           .filter_map(|(is, qualifier)| if is { Some(qualifier) } else { None })
+                                           .collect::<Vec<&'static str>>()
+}
+
+/// The function `sig2func` returns the visibility, name, arguments, result, receiver
+/// and qualifiers of a method or a free function.
+fn sig2func<'a>(vis: &'a syn::Visibility, sig: &syn::Signature) -> (&'a syn::Visibility, String, Vec<String>, Option<String>, Receiver, Vec<&'static str>) {
+    let syn::Signature {ref ident, ref inputs, ref output, ..} = *sig;
+
+    (vis, ident.to_string(), inputs.iter().map(|ref arg| arg_to_string(&arg)).collect::<Vec<String>>(), output_to_ty(output).map(ty_to_string), Receiver::from(sig), sig2qualifiers(sig))
+}
+
+/// The function `underline` returns an escaped text underlined by combining characters,
+/// like the static operations from [UML 2.5](http://www.omg.org/spec/UML/2.5).
+fn underline(text: &str) -> String {
+    let mut underlined: String = String::new();
+    let mut entity: bool = false;
+
+    for c in text.chars() {
+        underlined.push(c);
+        match c {
+            '&' => entity = true,
+            ';' if entity => {
+                entity = false;
+                underlined.push_str("&#818;");
+            },
+            _ if entity => {},
+            _ => underlined.push_str("&#818;"),
+        }
     }
+    underlined
+}
+
+/// The function `qualify` returns the escaped operation `func` marked by its qualifiers,
+/// and underlined when it takes no receiver.
+pub fn qualify(func: &str, receiver: Receiver, qualifiers: &[&'static str]) -> String {
+    let func: String = escape_html(func);
+    let qualifiers: String = qualifiers.iter()
+                                       .map(|qualifier| format!("&lt;&lt;&lt;{}&gt;&gt;&gt; ", qualifier))
+                                       .collect::<String>();
 
-    pub fn is_dependency(&self, name: &String) -> bool {
-        self.func.iter()
-                 .any(|&(_, _, ref arg, _): &(&'a ast::Visibility, InternedString, Vec<String>, Option<String>)|
-                     arg.iter().any(|ty| ty.ends_with(name)))
+    if receiver == Receiver::None {
+        format!("{}{}", qualifiers, underline(&func))
+    } else {
+        format!("{}{}", qualifiers, func)
     }
 }
 
-impl <'a> From<(Vec<(&'a ast::Visibility, InternedString, Vec<String>, Option<String>)>, Rc<ModulePath>)> for Method<'a> {
-    fn from((func, path): (Vec<(&'a ast::Visibility, InternedString, Vec<String>, Option<String>)>, Rc<ModulePath>)) -> Method<'a> {
+/// The structure `Method` is a collection of methods from a abstract element.
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Method <'a> {
+    /// visibility, method's name, arguments, result, receiver, qualifiers.
+    func: Vec<(&'a syn::Visibility, String, Vec<String>, Option<String>, Receiver, Vec<&'static str>)>,
+    path: Rc<ModulePath>,
+}
+
+impl <'a> From<(Vec<(&'a syn::Visibility, String, Vec<String>, Option<String>, Receiver, Vec<&'static str>)>, Rc<ModulePath>)> for Method<'a> {
+    fn from((func, path): (Vec<(&'a syn::Visibility, String, Vec<String>, Option<String>, Receiver, Vec<&'static str>)>, Rc<ModulePath>)) -> Method<'a> {
         Method {
             func: func,
             path: path,
@@ -50,23 +108,28 @@ impl <'a> From<(Vec<(&'a ast::Visibility, InternedString, Vec<String>, Option<St
     }
 }
 
-impl <'a> From<(&'a Vec<ast::ImplItem>, Rc<ModulePath>)> for Method<'a> {
-    fn from((impl_item, path): (&'a Vec<ast::ImplItem>, Rc<ModulePath>)) -> Method<'a> {
+impl <'a> From<(&'a Vec<syn::ImplItem>, Rc<ModulePath>)> for Method<'a> {
+    fn from((impl_item, path): (&'a Vec<syn::ImplItem>, Rc<ModulePath>)) -> Method<'a> {
         Method::from((impl_item.iter()
-                              .filter_map(|&ast::ImplItem {id: _, ident: ast::Ident { name, ..}, ref vis, defaultness: _, attrs: _, ref node, .. }| {
-                                     if let &ast::ImplItemKind::Method(ast::MethodSig {unsafety: _, constness: _, abi: _, ref decl, ..}, _) = node {
-                                         if let &ast::FnDecl {ref inputs, output: ast::FunctionRetTy::Ty(ref ty), ..} = decl.deref() {
-                                             Some((vis, name.as_str(), inputs.iter().map(|ref arg| arg_to_string(&arg)).collect::<Vec<String>>(), Some(ty_to_string(&ty))))
-                                         } else if let &ast::FnDecl {ref inputs, output: ast::FunctionRetTy::Default(_), ..} = decl.deref() {
-                                             Some((vis, name.as_str(), inputs.iter().map(|ref arg| arg_to_string(&arg)).collect::<Vec<String>>(), None))
-                                         } else {
-                                             None
-                                         }
+                              .filter_map(|impl_item: &'a syn::ImplItem| {
+                                     if let &syn::ImplItem::Fn(syn::ImplItemFn {ref vis, ref sig, ..}) = impl_item {
+                                         Some(sig2func(vis, sig))
                                      } else {
                                          None
                                      }
                                })
-                               .collect::<Vec<(&'a ast::Visibility, InternedString, Vec<String>, Option<String>)>>(),
+                               .collect::<Vec<(&'a syn::Visibility, String, Vec<String>, Option<String>, Receiver, Vec<&'static str>)>>(),
+                      path))
+    }
+}
+
+impl <'a> From<(Vec<&'a syn::ItemFn>, Rc<ModulePath>)> for Method<'a> {
+
+    /// The constructor method `from` returns the free functions `item_fn` of the module `path`.
+    fn from((item_fn, path): (Vec<&'a syn::ItemFn>, Rc<ModulePath>)) -> Method<'a> {
+        Method::from((item_fn.into_iter()
+                             .map(|&syn::ItemFn {ref vis, ref sig, ..}| sig2func(vis, sig))
+                             .collect::<Vec<(&'a syn::Visibility, String, Vec<String>, Option<String>, Receiver, Vec<&'static str>)>>(),
                       path))
     }
 }
@@ -74,26 +137,13 @@ impl <'a> From<(&'a Vec<ast::ImplItem>, Rc<ModulePath>)> for Method<'a> {
 impl <'a>fmt::Display for Method<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{item}",
-            item = escape_html(self.func.iter()
-                                        .map(|&(ref vis, ref name, ref inputs, ref ty)|
-                                               match (vis, ty) {
-                                                   (&&ast::Visibility::Public, &Some(ref ty)) => {
-                                                       format!("+{}{}({}) -> {}", DEFAULT_FUNC, name, inputs.iter().map(|arg| arg.to_string()).collect::<Vec<String>>().join(", "), ty)
-                                                   },
-                                                   (&&ast::Visibility::Public, &None) => {
-                                                       format!("+{}{}({})", DEFAULT_FUNC, name, inputs.iter().map(|arg| arg.to_string()).collect::<Vec<String>>().join(", "))
-                                                   },
-                                                   (_, &Some(ref ty)) => {
-                                                       format!("-{}{}({}) -> {}", DEFAULT_FUNC, name, inputs.iter().map(|arg| arg.to_string()).collect::<Vec<String>>().join(", "), ty)
-                                                   },
-                                                   (_, &None) => {
-                                                       format!("-{}{}({})", DEFAULT_FUNC, name, inputs.iter().map(|arg| arg.to_string()).collect::<Vec<String>>().join(", "))
-                                                   },
-                                               }
-                                           )
-                                           .collect::<Vec<String>>()
-                                           .join("\n")
-                                           .as_str())
+            item = self.func.iter()
+                            .map(|&(ref vis, ref name, ref inputs, ref ty, receiver, ref qualifiers)|
+                                 qualify(&format!("{}{}{}({}){}", vis_to_marker(vis), DEFAULT_FUNC, name, inputs.join(", "),
+                                             ty.as_ref().map(|ty| format!(" -> {}", ty)).unwrap_or_default()),
+                                         receiver, qualifiers))
+                            .collect::<Vec<String>>()
+                            .join("\n")
         )
     }
 }
@@ -1,6 +1,9 @@
 pub mod abstraction;
 pub mod implem;
 pub mod method;
+pub mod multiplicity;
+pub mod ownership;
+pub mod reference;
 
 #[cfg(not(feature = "fn-emilgardis"))]
 const DEFAULT_FUNC: &'static str = " ";
@@ -8,8 +11,11 @@ const DEFAULT_FUNC: &'static str = " ";
 const DEFAULT_FUNC: &'static str = " fn ";
 
 use self::abstraction::Abstract;
+use self::abstraction::stub::Stub;
 use self::implem::Implem;
 use self::method::Method;
+use self::multiplicity::Multiplicity;
+use self::reference::Reference;
 
 use super::relation::Relation;
 
@@ -17,10 +23,16 @@ use std::ops::BitOr;
 use std::fmt;
 use std::rc::Rc;
 
-use ::syntex_syntax::symbol::InternedString;
-use ::syntex_syntax::{ptr, ast};
+use ::syn;
+use ::dot::escape_html;
+
+use ::core::print::{meta_to_string, path_to_string};
+use ::core::item::item2derives;
+
+use ::core::model::Model;
 
 use ::module::path::ModulePath;
+use ::module::scope::Scope;
 
 /// The structure `ItemState` describes an abstract element with a collections of methodes
 /// and implementations.
@@ -32,6 +44,14 @@ pub struct ItemState<'a> {
     method: Vec<Method<'a>>,
     /// Implementation of Trait.
     implem: Vec<Implem>,
+    /// Types referenced by the element and its implementations.
+    reference: Reference,
+    /// Configuration predicates kept as `#[doc(cfg(...))]`.
+    conditions: Vec<String>,
+    /// Derived traits, shown unless the feature `derive-realization` draws them as realizations.
+    derive: Vec<String>,
+    /// First paragraph of the documentation of the element, or else of its named member.
+    doc: Vec<(Option<String>, String)>,
 }
 
 impl <'a> ItemState <'a> {
@@ -40,70 +60,46 @@ impl <'a> ItemState <'a> {
         self.node.eq(&Abstract::None)
     }
 
-    pub fn is_association(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let ref ty_name: String = name.to_string();
-
-            rhs.method.iter()
-                      .any(|func| func.is_association(ty_name))
-                      .bitor(rhs.implem.iter()
-                                       .any(|implem| implem.is_association(&ty_name)))
+    /// The method `is_utility` returns true when the abstract element holds the free functions of a module.
+    pub fn is_utility(&self) -> bool {
+        if let Abstract::Utility(_) = self.node {
+            true
         } else {
             false
         }
     }
 
-    pub fn is_dependency(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let ref ty_name: String = name.to_string();
+    pub fn is_association(&self, rhs: &ItemState<'a>) -> bool {
+        self.as_path().map_or(false, |ref path| rhs.reference.association.iter()
+                                                                         .chain(rhs.reference.weak.iter())
+                                                                         .any(|&(ref ty, _, _)| ty.eq(path)))
+    }
 
-            rhs.method.iter()
-                      .any(|method| method.is_dependency(&ty_name))
-                      .bitor(self.implem.iter()
-                                        .any(|implem| implem.is_dependency(&ty_name)))
-        } else {
-            false
-        }
+    pub fn is_dependency(&self, rhs: &ItemState<'a>) -> bool {
+        self.as_path().map_or(false, |ref path| rhs.reference.dependency.contains(path))
     }
 
     pub fn is_aggregation(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let mut ty_name_mut: String = String::from("*mut ");
-            let mut ty_name_const: String = String::from("*const ");
-            
-            ty_name_mut.push_str(&name);
-            ty_name_const.push_str(&name);
-            rhs.node.into_iter()
-                    .any(|attribut: &String|
-                          attribut.split(|at| "<[(;,)]>".contains(at))
-                                  .any(|ty| ty_name_mut.eq(ty).bitor(ty_name_const.eq(ty))))
-        } else {
-            false
-        }
+        self.as_path().map_or(false, |ref path| rhs.reference.aggregation.iter().any(|&(ref ty, _, _)| ty.eq(path)))
     }
 
     pub fn is_composition(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let ty_name: String = name.to_string();
-
-            rhs.node.into_iter()
-                    .any(|attribut: &String|
-                          attribut.split(|at| "<[(;,)]>".contains(at))
-                                  .any(|ty| ty.eq(&ty_name)))
-        } else {
-            false
-        }
+        self.as_path().map_or(false, |ref path| rhs.reference.composition.iter().any(|&(ref ty, _, _)| ty.eq(path)))
     }
 
     pub fn is_realization(&self, rhs: &ItemState<'a>) -> bool {
-        if let Some(ref name) = self.as_name() {
-            let ty_name: String = name.to_string();
+        self.as_path().map_or(false, |ref path| rhs.reference.realization.contains(path))
+    }
 
-            rhs.implem.iter()
-                      .any(|implem| implem.is_realization(&ty_name))
-        } else {
-            false
-        }
+    pub fn is_generalization(&self, rhs: &ItemState<'a>) -> bool {
+        self.as_path().map_or(false, |ref path| rhs.reference.generalization.contains(path))
+    }
+
+    /// The method `is_reflexive` returns true when the fields of the abstract element hold
+    /// the element itself, like a tree or a linked list, owned, shared or weakly.
+    pub fn is_reflexive(&self) -> bool {
+        self.is_composition(self).bitor(self.is_aggregation(self))
+                                 .bitor(self.as_path().map_or(false, |ref path| self.reference.weak.iter().any(|&(ref ty, _, _)| ty.eq(path))))
     }
 
     pub fn is_relation(&self, rhs: &ItemState<'a>) -> bool {
@@ -112,68 +108,184 @@ impl <'a> ItemState <'a> {
             .bitor(self.is_aggregation(rhs))
             .bitor(self.is_composition(rhs))
             .bitor(self.is_realization(rhs))
+            .bitor(self.is_generalization(rhs))
     }
 
-    pub fn as_name(&self) -> Option<&InternedString> {
+    pub fn as_name(&self) -> Option<&String> {
         self.node.as_name()
     }
 
+    /// The method `as_path` returns the module's path of the abstract element followed by its name.
+    pub fn as_path(&self) -> Option<ModulePath> {
+        self.node.as_path()
+    }
+
     pub fn as_arrow(&self, rhs: &ItemState<'a>) -> Relation {
         Relation::from((self, rhs))
     }
+
+    /// The method `as_roles` returns the roles, with their multiplicities, of the abstract element
+    /// held by `rhs`, merged from its compositions, aggregations and associations.
+    pub fn as_roles(&self, rhs: &ItemState<'a>) -> Vec<(String, Multiplicity)> {
+        self.as_path()
+            .map(|path| rhs.reference.composition.iter()
+                                               .chain(rhs.reference.aggregation.iter())
+                                               .chain(rhs.reference.association.iter())
+                                               .chain(rhs.reference.weak.iter())
+                                               .filter(|&&(ref ty, _, _)| ty.eq(&path))
+                                               .map(|&(_, ref role, ref multiplicity)| (role.clone(), multiplicity.clone()))
+                                               .collect::<Vec<(String, Multiplicity)>>())
+            .unwrap_or_default()
+    }
+
+    /// The method `as_doc` returns the documentation of the abstract element followed by
+    /// the documentation of its members.
+    pub fn as_doc(&self) -> Option<String> {
+        if self.doc.is_empty() {
+            None
+        } else {
+            Some(self.doc.iter()
+                         .map(|&(ref member, ref doc)| match member {
+                             &Some(ref member) => format!("{}: {}", member, doc),
+                             &None => doc.clone(),
+                         })
+                         .collect::<Vec<String>>()
+                         .join("\n"))
+        }
+    }
+
+    /// The method `stereotypes` returns the configuration predicates and the derived traits
+    /// shown above the abstract element.
+    fn stereotypes(&self) -> String {
+        self.conditions.iter()
+                       .map(|condition| format!("&lt;&lt;&lt;cfg({})&gt;&gt;&gt;\n", condition))
+                       .chain(Some(&self.derive).into_iter()
+                                                .filter(|derive| !derive.is_empty())
+                                                .map(|derive| format!("&lt;&lt;&lt;derive({})&gt;&gt;&gt;\n", escape_html(&derive.join(", ")))))
+                       .collect::<String>()
+    }
 }
 
-impl <'a>From<(Abstract<'a>, Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>)> for ItemState<'a> {
-    fn from((node, properties): (Abstract<'a>, Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>)) -> ItemState<'a> {
+impl <'a, 'b>From<((Abstract<'a>, Vec<&'a (syn::Item, Rc<ModulePath>)>, Reference), &'b Scope<'a>)> for ItemState<'a> {
+    fn from(((node, properties, reference), scope): ((Abstract<'a>, Vec<&'a (syn::Item, Rc<ModulePath>)>, Reference), &'b Scope<'a>)) -> ItemState<'a> {
         ItemState {
             node: node,
             method: properties.iter()
-                .filter_map(|&&(ref item, ref path): (&&'a (ptr::P<ast::Item>, Rc<ModulePath>))|
-                    if let ast::ItemKind::Impl(_, _, _, _, None, _, ref impl_item) = item.node {
-                        Some(Method::from((impl_item, Rc::clone(path))))
+                .filter_map(|&&(ref item, ref path): &&'a (syn::Item, Rc<ModulePath>)|
+                    if let &syn::Item::Impl(syn::ItemImpl {trait_: None, ref items, ..}) = item {
+                        Some(Method::from((items, Rc::clone(path))))
                     } else {
                         None
                     }
                 )
                 .collect::<Vec<Method>>(),
             implem: properties.iter()
-                .filter_map(|&&(ref item, _): (&&'a (ptr::P<ast::Item>, Rc<ModulePath>))|
-                    if let ast::ItemKind::Impl(_, _, _, _, Some(ast::TraitRef {path: ast::Path {span: _, ref segments}, ..}), _, ref impl_item) = item.node {
-                        Some(Implem::from((segments, impl_item)))
+                .filter_map(|&&(ref item, ref path): &&'a (syn::Item, Rc<ModulePath>)|
+                    if let &syn::Item::Impl(syn::ItemImpl {trait_: Some((_, ref trait_, _)), ref items, ..}) = item {
+                        let definition: Option<&'a syn::ItemTrait> = scope.resolve_path(path, trait_)
+                                                                          .and_then(|trait_| scope.definition(&trait_))
+                                                                          .and_then(|item| if let &syn::Item::Trait(ref item) = item {
+                                                                              Some(item)
+                                                                          } else {
+                                                                              None
+                                                                          });
+
+                        Some(Implem::from((&trait_.segments, items, definition)))
                     } else {
                         None
                     }
                 )
-                .collect::<Vec<Implem>>()
+                .collect::<Vec<Implem>>(),
+            reference: reference,
+            conditions: Vec::new(),
+            derive: Vec::new(),
+            doc: Vec::new(),
         }
     }
 }
 
-impl <'a>From<Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>> for ItemState<'a> {
-    fn from(state: Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>) -> ItemState<'a> {
-        state.split_first().and_then(|(&&(ref item, ref path), properties): (&&'a (ptr::P<ast::Item>, Rc<ModulePath>), &[&'a (ptr::P<ast::Item>, Rc<ModulePath>)])| {
-            match &item.node {
-                /// Trait.
-                &ast::ItemKind::Trait(_, ast::Generics {lifetimes: _, ref ty_params, ..}, _, ref trait_item) => {
-                    let kind: (&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::TraitItem>) = (item, ty_params, trait_item);
-                    let kind: (Abstract, Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>) = (Abstract::from((kind, Rc::clone(path))), properties.to_vec());
-                    Some(ItemState::from(kind))
+impl <'a, 'b>From<(Vec<&'a (syn::Item, Rc<ModulePath>)>, &'b Scope<'a>, &'b Model)> for ItemState<'a> {
+    fn from((state, scope, model): (Vec<&'a (syn::Item, Rc<ModulePath>)>, &'b Scope<'a>, &'b Model)) -> ItemState<'a> {
+        let reference: Reference = Reference::from((state.as_slice(), scope, model));
+        let conditions: Vec<String> = state.first()
+                                           .map(|&&(ref item, _)| item2conditions(item))
+                                           .unwrap_or_default();
+        let derive: Vec<String> = state.first()
+                                       .filter(|_| cfg!(not(feature = "derive-realization")))
+                                       .map(|&&(ref item, _)| item2derives(item).iter()
+                                                                                 .map(path_to_string)
+                                                                                 .collect::<Vec<String>>())
+                                       .unwrap_or_default();
+        let doc: Vec<(Option<String>, String)> = list2doc(&state);
+
+        state.split_first().and_then(|(&&(ref item, ref path), properties): (&&'a (syn::Item, Rc<ModulePath>), &[&'a (syn::Item, Rc<ModulePath>)])| {
+            match item {
+                // Trait.
+                &syn::Item::Trait(ref item) => {
+                    let kind: (Abstract, Vec<&'a (syn::Item, Rc<ModulePath>)>, Reference) = (Abstract::from((item, Rc::clone(path))), properties.to_vec(), reference);
+                    Some(ItemState::from((kind, scope)))
+                },
+                // Structure with named, positional or without variables.
+                &syn::Item::Struct(ref item) => {
+                    let kind: (Abstract, Vec<&'a (syn::Item, Rc<ModulePath>)>, Reference) = (Abstract::from((item, Rc::clone(path))), properties.to_vec(), reference);
+                    Some(ItemState::from((kind, scope)))
                 },
-                /// Structure with variables.
-                &ast::ItemKind::Struct(ast::VariantData::Struct(ref struct_field, _), ..) => {
-                    let kind: (&'a ast::Item, &'a Vec<ast::StructField>) = (item, struct_field);
-                    let kind: (Abstract, Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>) = (Abstract::from((kind, Rc::clone(path))), properties.to_vec());
-                    Some(ItemState::from(kind))
+                // Enumeration with variables.
+                &syn::Item::Enum(ref item) => {
+                    let kind: (Abstract, Vec<&'a (syn::Item, Rc<ModulePath>)>, Reference) = (Abstract::from((item, Rc::clone(path))), properties.to_vec(), reference);
+                    Some(ItemState::from((kind, scope)))
                 },
-                /// Enumeration with variables.
-                &ast::ItemKind::Enum(ast::EnumDef {ref variants}, ast::Generics {lifetimes: _, ref ty_params, ..}) => {
-                    let kind: (&'a ast::Item, &'a Vec<ast::TyParam>, &'a Vec<ast::Variant>) = (item, ty_params, variants);
-                    let kind: (Abstract, Vec<&'a (ptr::P<ast::Item>, Rc<ModulePath>)>) = (Abstract::from((kind, Rc::clone(path))), properties.to_vec());
-                    Some(ItemState::from(kind))
+                // Union with variables.
+                &syn::Item::Union(ref item) => {
+                    let kind: (Abstract, Vec<&'a (syn::Item, Rc<ModulePath>)>, Reference) = (Abstract::from((item, Rc::clone(path))), properties.to_vec(), reference);
+                    Some(ItemState::from((kind, scope)))
+                },
+                // Type alias.
+                &syn::Item::Type(ref item) => {
+                    let kind: (Abstract, Vec<&'a (syn::Item, Rc<ModulePath>)>, Reference) = (Abstract::from((item, Rc::clone(path))), properties.to_vec(), reference);
+                    Some(ItemState::from((kind, scope)))
+                },
+                // Free functions of a module.
+                &syn::Item::Fn(_) => {
+                    let kind: (Abstract, Vec<&'a (syn::Item, Rc<ModulePath>)>, Reference) = (Abstract::from(Rc::clone(path)), Vec::new(), reference);
+                    let item_fn: Vec<&'a syn::ItemFn> = state.iter()
+                                                             .filter_map(|&&(ref item, _)| if let &syn::Item::Fn(ref item) = item {
+                                                                 Some(item)
+                                                             } else {
+                                                                 None
+                                                             })
+                                                             .collect::<Vec<&'a syn::ItemFn>>();
+
+                    Some(ItemState {
+                        method: vec!(Method::from((item_fn, Rc::clone(path)))),
+                        ..ItemState::from((kind, scope))
+                    })
                 },
                 _ => None,
             }
-        }).unwrap_or_default()
+        }).map(|state| ItemState { conditions: conditions, derive: derive, doc: doc, ..state })
+          .unwrap_or_default()
+    }
+}
+
+impl <'a, 'b>From<((&'a syn::ForeignItemType, Rc<ModulePath>), Vec<&'a (syn::Item, Rc<ModulePath>)>, &'b Scope<'a>, &'b Model)> for ItemState<'a> {
+    fn from((opaque, properties, scope, model): ((&'a syn::ForeignItemType, Rc<ModulePath>), Vec<&'a (syn::Item, Rc<ModulePath>)>, &'b Scope<'a>, &'b Model)) -> ItemState<'a> {
+        let reference: Reference = Reference::from((properties.as_slice(), scope, model));
+
+        ItemState {
+            conditions: attrs2conditions(&opaque.0.attrs),
+            doc: attrs2doc(&opaque.0.attrs).map(|doc| vec!((None, doc))).unwrap_or_default(),
+            ..ItemState::from(((Abstract::from(opaque), properties, reference), scope))
+        }
+    }
+}
+
+impl <'a>From<Stub> for ItemState<'a> {
+    fn from(stub: Stub) -> ItemState<'a> {
+        ItemState {
+            node: Abstract::from(stub),
+            ..ItemState::default()
+        }
     }
 }
 
@@ -181,7 +293,8 @@ impl <'a>fmt::Display for ItemState<'a> {
 
     #[cfg(feature = "implem")]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{{{node}|{method}|{implem}}}",
+        write!(f, "{{{stereotypes}{node}|{method}|{implem}}}",
+            stereotypes = self.stereotypes(),
             node = self.node,
             method = self.method.iter()
                                 .map(|ref methods| format!("{}", methods))
@@ -194,9 +307,10 @@ impl <'a>fmt::Display for ItemState<'a> {
     #[cfg(not(feature = "implem"))]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.method.is_empty() {
-            write!(f, "{{{node}}}", node = self.node)
+            write!(f, "{{{stereotypes}{node}}}", stereotypes = self.stereotypes(), node = self.node)
         } else {
-            write!(f, "{{{node}|{method}}}",
+            write!(f, "{{{stereotypes}{node}|{method}}}",
+                stereotypes = self.stereotypes(),
                 node = self.node,
                 method = self.method.iter()
                                     .map(|ref methods| format!("{}", methods))
@@ -204,3 +318,115 @@ impl <'a>fmt::Display for ItemState<'a> {
         }
     }
 }
+
+/// The function `item2conditions` returns the configuration predicates of a type's definition.
+fn item2conditions(item: &syn::Item) -> Vec<String> {
+    match item {
+        &syn::Item::Trait(syn::ItemTrait { ref attrs, .. }) |
+        &syn::Item::Struct(syn::ItemStruct { ref attrs, .. }) |
+        &syn::Item::Enum(syn::ItemEnum { ref attrs, .. }) |
+        &syn::Item::Union(syn::ItemUnion { ref attrs, .. }) |
+        &syn::Item::Type(syn::ItemType { ref attrs, .. }) => attrs2conditions(attrs),
+        _ => Vec::new(),
+    }
+}
+
+/// The function `attrs2conditions` returns the configuration predicates written
+/// as `#[doc(cfg(...))]` attributes.
+fn attrs2conditions(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs.iter()
+         .filter(|attr| attr.path().is_ident("doc"))
+         .filter_map(|attr| attr.parse_args::<syn::MetaList>().ok())
+         .filter(|list| list.path.is_ident("cfg"))
+         .filter_map(|list| list.parse_args::<syn::Meta>().ok())
+         .map(|predicate| escape_html(&meta_to_string(&predicate)))
+         .collect::<Vec<String>>()
+}
+
+/// The function `list2doc` returns the documentation of a type's definition followed by
+/// the documentation of its fields, variants and methods, or of the free functions.
+fn list2doc(list: &[&(syn::Item, Rc<ModulePath>)]) -> Vec<(Option<String>, String)> {
+    let mut doc: Vec<(Option<String>, Option<String>)> = Vec::new();
+
+    for &&(ref item, _) in list.iter() {
+        match item {
+            &syn::Item::Trait(syn::ItemTrait { ref attrs, ref items, .. }) => {
+                doc.push((None, attrs2doc(attrs)));
+                doc.extend(items.iter()
+                                .filter_map(|item| match item {
+                                    &syn::TraitItem::Const(syn::TraitItemConst { ref attrs, ref ident, .. }) |
+                                    &syn::TraitItem::Type(syn::TraitItemType { ref attrs, ref ident, .. }) => Some((ident, attrs)),
+                                    &syn::TraitItem::Fn(syn::TraitItemFn { ref attrs, sig: syn::Signature { ref ident, .. }, .. }) => Some((ident, attrs)),
+                                    _ => None,
+                                })
+                                .map(|(ident, attrs)| (Some(ident.to_string()), attrs2doc(attrs))));
+            },
+            &syn::Item::Struct(syn::ItemStruct { ref attrs, ref fields, .. }) => {
+                doc.push((None, attrs2doc(attrs)));
+                doc.extend(fields.iter()
+                                 .enumerate()
+                                 .map(|(index, field)| (Some(field.ident.as_ref()
+                                                                        .map(|ident| ident.to_string())
+                                                                        .unwrap_or_else(|| index.to_string())),
+                                                        attrs2doc(&field.attrs))));
+            },
+            &syn::Item::Union(syn::ItemUnion { ref attrs, ref fields, .. }) => {
+                doc.push((None, attrs2doc(attrs)));
+                doc.extend(fields.named
+                                 .iter()
+                                 .map(|field| (field.ident.as_ref().map(|ident| ident.to_string()), attrs2doc(&field.attrs))));
+            },
+            &syn::Item::Enum(syn::ItemEnum { ref attrs, ref variants, .. }) => {
+                doc.push((None, attrs2doc(attrs)));
+                doc.extend(variants.iter()
+                                   .map(|variant| (Some(variant.ident.to_string()), attrs2doc(&variant.attrs))));
+            },
+            &syn::Item::Type(syn::ItemType { ref attrs, .. }) => {
+                doc.push((None, attrs2doc(attrs)));
+            },
+            &syn::Item::Fn(syn::ItemFn { ref attrs, ref sig, .. }) => {
+                doc.push((Some(sig.ident.to_string()), attrs2doc(attrs)));
+            },
+            &syn::Item::Impl(syn::ItemImpl { ref items, .. }) => {
+                doc.extend(items.iter()
+                                .filter_map(|item| if let &syn::ImplItem::Fn(syn::ImplItemFn { ref attrs, ref sig, .. }) = item {
+                                    Some((Some(sig.ident.to_string()), attrs2doc(attrs)))
+                                } else {
+                                    None
+                                }));
+            },
+            _ => {},
+        }
+    }
+    doc.into_iter()
+       .filter_map(|(member, doc)| doc.map(|doc| (member, doc)))
+       .collect::<Vec<(Option<String>, String)>>()
+}
+
+/// The function `attrs2doc` returns the first paragraph of the documentation written
+/// as `///` comments or `#[doc = "..."]` attributes.
+fn attrs2doc(attrs: &[syn::Attribute]) -> Option<String> {
+    let doc: String = attrs.iter()
+                           .filter(|attr| attr.path().is_ident("doc"))
+                           .filter_map(|attr| if let syn::Meta::NameValue(syn::MetaNameValue {
+                               value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(ref doc), .. }), ..
+                           }) = attr.meta {
+                               Some(doc.value())
+                           } else {
+                               None
+                           })
+                           .collect::<Vec<String>>()
+                           .join("\n");
+    let paragraph: String = doc.lines()
+                               .map(str::trim)
+                               .skip_while(|line| line.is_empty())
+                               .take_while(|line| !line.is_empty())
+                               .collect::<Vec<&str>>()
+                               .join(" ");
+
+    if paragraph.is_empty() {
+        None
+    } else {
+        Some(paragraph)
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+
+/// The default multiplicities of the standard containers, looked for by the name of the type;
+/// the smart pointers and cells are written `1` like a type without container.
+pub const DEFAULT_CONTAINERS: [(&'static str, &'static str); 18] = [
+    ("Option", "0..1"),
+    ("Vec", "0..*"),
+    ("VecDeque", "0..*"),
+    ("LinkedList", "0..*"),
+    ("BinaryHeap", "0..*"),
+    ("HashSet", "0..*"),
+    ("BTreeSet", "0..*"),
+    ("HashMap", "0..*"),
+    ("BTreeMap", "0..*"),
+    ("Box", "1"),
+    ("Rc", "1"),
+    ("Arc", "1"),
+    ("Weak", "0..1"),
+    ("Cell", "1"),
+    ("RefCell", "1"),
+    ("Mutex", "1"),
+    ("RwLock", "1"),
+    ("Cow", "1"),
+];
+
+/// The structure `Multiplicity` is the range of instances held at an end of a relation,
+/// from [UML 2.5](http://www.omg.org/spec/UML/2.5).
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Multiplicity {
+    /// Lower bound.
+    lower: String,
+    /// Upper bound, `*` when unlimited.
+    upper: String,
+}
+
+impl Multiplicity {
+
+    /// The method `times` returns the multiplicity of an element held by a container
+    /// of multiplicity `self`, itself holding `rhs` elements.
+    pub fn times(&self, rhs: &Multiplicity) -> Multiplicity {
+        Multiplicity {
+            lower: bound_times(&self.lower, &rhs.lower),
+            upper: bound_times(&self.upper, &rhs.upper),
+        }
+    }
+}
+
+impl Default for Multiplicity {
+    fn default() -> Multiplicity {
+        Multiplicity::from("1")
+    }
+}
+
+impl <'a> From<&'a str> for Multiplicity {
+
+    /// The constructor method `from` reads a multiplicity written like `1`, `0..1`, `*` or `0..*`.
+    fn from(multiplicity: &'a str) -> Multiplicity {
+        match multiplicity.find("..") {
+            Some(index) => Multiplicity {
+                lower: multiplicity[..index].trim().to_string(),
+                upper: multiplicity[index + 2..].trim().to_string(),
+            },
+            None if multiplicity.trim().eq("*") => Multiplicity {
+                lower: String::from("0"),
+                upper: String::from("*"),
+            },
+            None => Multiplicity {
+                lower: multiplicity.trim().to_string(),
+                upper: multiplicity.trim().to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Multiplicity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.lower.eq(&self.upper) {
+            write!(f, "{}", self.lower)
+        } else {
+            write!(f, "{}..{}", self.lower, self.upper)
+        }
+    }
+}
+
+/// The function `bound_times` returns the product of two bounds, kept symbolic
+/// when a bound is a constant's name or when the product overflows.
+fn bound_times(lhs: &str, rhs: &str) -> String {
+    match (lhs, rhs) {
+        ("0", _) | (_, "0") => String::from("0"),
+        ("1", bound) | (bound, "1") => bound.to_string(),
+        ("*", _) | (_, "*") => String::from("*"),
+        (lhs, rhs) => match (lhs.parse::<u64>(), rhs.parse::<u64>()) {
+            (Ok(lhs), Ok(rhs)) => lhs.checked_mul(rhs)
+                                     .map_or_else(|| format!("{}*{}", lhs, rhs), |product| product.to_string()),
+            _ => format!("{}*{}", lhs, rhs),
+        },
+    }
+}
@@ -0,0 +1,25 @@
+/// The default ownerships of the standard smart pointers, looked for by the name of the type.
+pub const DEFAULT_POINTERS: [(&'static str, Ownership); 4] = [
+    ("Box", Ownership::Owned),
+    ("Rc", Ownership::Shared),
+    ("Arc", Ownership::Shared),
+    ("Weak", Ownership::Weak),
+];
+
+/// The enumeration `Ownership` is how a field holds a type, ordered from the strongest
+/// to the weakest hold.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Ownership {
+    /// Inline or boxed, drawn as a composition.
+    Owned,
+    /// Borrowed, raw or counted pointer, drawn as an aggregation.
+    Shared,
+    /// Weak pointer, drawn as an association.
+    Weak,
+}
+
+impl Default for Ownership {
+    fn default() -> Ownership {
+        Ownership::Owned
+    }
+}
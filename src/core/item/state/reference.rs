@@ -0,0 +1,314 @@
+use std::cmp;
+use std::rc::Rc;
+
+use ::syn;
+
+use ::core::print::{arg_to_ty, expr_to_string};
+use ::core::item::{item2derives, derive2path};
+
+use ::module::path::ModulePath;
+use ::core::model::Model;
+use ::module::scope::Scope;
+
+use super::multiplicity::Multiplicity;
+use super::ownership::Ownership;
+
+/// The structure `Reference` is the collection of types' paths referenced by an abstract element,
+/// resolved from the modules where they are written.
+
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Reference {
+    /// Types owned by the fields, inline or boxed, with the fields' roles and multiplicities.
+    pub composition: Vec<(ModulePath, String, Multiplicity)>,
+    /// Types shared by the fields' references, raw and counted pointers, with the fields' roles
+    /// and multiplicities.
+    pub aggregation: Vec<(ModulePath, String, Multiplicity)>,
+    /// Types returned by the methods and free functions, with their names like `len()`
+    /// and multiplicities.
+    pub association: Vec<(ModulePath, String, Multiplicity)>,
+    /// Types weakly pointed by the fields, drawn as associations, with the fields' roles
+    /// and multiplicities.
+    pub weak: Vec<(ModulePath, String, Multiplicity)>,
+    /// Types named by the arguments of the methods and free functions, with their generic arguments,
+    /// and traits bounding the template's and the functions' parameters.
+    pub dependency: Vec<ModulePath>,
+    /// Implemented traits, and derived traits with the feature `derive-realization`.
+    pub realization: Vec<ModulePath>,
+    /// Supertraits.
+    pub generalization: Vec<ModulePath>,
+}
+
+impl Reference {
+
+    /// The method `fields` adds the types of the fields `fields` of the item `ident` written from
+    /// the module `path`, with the roles of the fields, classified by how they are held.
+    fn fields<'a, I: Iterator<Item = (String, &'a syn::Type)>>(&mut self, fields: I, ident: &syn::Ident, path: &ModulePath, scope: &Scope, model: &Model, params: &[String]) {
+        let itself: syn::Path = syn::Path::from(ident.clone());
+
+        for (role, ty) in fields {
+            for (ownership, ty, multiplicity) in ty2paths(ty, model) {
+                let ty: &syn::Path = if ty.is_ident("Self") { &itself } else { ty };
+
+                if let Some(ty) = resolve(scope, path, ty, params) {
+                    match ownership {
+                        Ownership::Owned => self.composition.push((ty, role.clone(), multiplicity)),
+                        Ownership::Shared => self.aggregation.push((ty, role.clone(), multiplicity)),
+                        Ownership::Weak => self.weak.push((ty, role.clone(), multiplicity)),
+                    }
+                }
+            }
+        }
+    }
+
+    /// The method `bounds` adds the traits bounding the template's parameters `generics`
+    /// written from the module `path`.
+    fn bounds(&mut self, generics: &syn::Generics, path: &ModulePath, scope: &Scope, params: &[String]) {
+        let bounds: Vec<&syn::TypeParamBound> = generics.type_params()
+                                                        .flat_map(|param| param.bounds.iter())
+                                                        .chain(generics.where_clause
+                                                                       .iter()
+                                                                       .flat_map(|clause| clause.predicates.iter())
+                                                                       .filter_map(|predicate| if let &syn::WherePredicate::Type(ref predicate) = predicate {
+                                                                           Some(predicate.bounds.iter())
+                                                                       } else {
+                                                                           None
+                                                                       })
+                                                                       .flat_map(|bounds| bounds))
+                                                        .collect::<Vec<&syn::TypeParamBound>>();
+
+        self.dependency.extend(bounds.into_iter()
+                                     .filter_map(|bound| if let &syn::TypeParamBound::Trait(syn::TraitBound { path: ref bound, .. }) = bound {
+                                         resolve(scope, path, bound, params)
+                                     } else {
+                                         None
+                                     }));
+    }
+
+    /// The method `methods` adds the types of the signatures `items` written from the module `path`,
+    /// with every type named by the arguments and the traits bounding the methods' parameters.
+    fn methods(&mut self, items: &[syn::ImplItem], path: &ModulePath, scope: &Scope, model: &Model, params: &[String]) {
+        for item in items.iter() {
+            if let &syn::ImplItem::Fn(syn::ImplItemFn { ref sig, .. }) = item {
+                self.signature(sig, path, scope, model, params);
+            }
+        }
+    }
+
+    /// The method `signature` adds the types of the method's or free function's signature `sig`
+    /// written from the module `path`: the result as an association named like `len()`,
+    /// the arguments and the traits bounding the parameters as dependencies.
+    fn signature(&mut self, sig: &syn::Signature, path: &ModulePath, scope: &Scope, model: &Model, params: &[String]) {
+        let syn::Signature { ref ident, ref generics, ref inputs, ref output, .. } = *sig;
+        let params: Vec<String> = params.iter()
+                                        .cloned()
+                                        .chain(generics2params(generics))
+                                        .collect::<Vec<String>>();
+
+        if let &syn::ReturnType::Type(_, ref ty) = output {
+            self.association.extend(ty2paths(ty, model).into_iter()
+                                                       .filter_map(|(_, ty, multiplicity)| resolve(scope, path, ty, &params)
+                                                                                                .map(|ty| (ty, format!("{}()", ident), multiplicity))));
+        }
+        self.bounds(generics, path, scope, &params);
+        self.dependency.extend(inputs.iter()
+                                     .flat_map(|arg| ty2paths(arg_to_ty(arg), model))
+                                     .filter_map(|(_, ty, _)| resolve(scope, path, ty, &params)));
+    }
+}
+
+impl <'a, 'b>From<(&'b [&'a (syn::Item, Rc<ModulePath>)], &'b Scope<'a>, &'b Model)> for Reference {
+    fn from((list, scope, model): (&'b [&'a (syn::Item, Rc<ModulePath>)], &'b Scope<'a>, &'b Model)) -> Reference {
+        let mut reference: Reference = Reference::default();
+
+        for &&(ref item, ref path) in list.iter() {
+            let params: Vec<String> = item2generics(item).map(generics2params)
+                                                         .unwrap_or_default();
+
+            if cfg!(feature = "derive-realization") {
+                reference.realization.extend(item2derives(item).iter()
+                                                               .map(|derive| scope.resolve_path(path, derive)
+                                                                                  .unwrap_or_else(|| derive2path(derive))));
+            }
+            match item {
+                &syn::Item::Trait(syn::ItemTrait { ref generics, ref supertraits, .. }) => {
+                    reference.bounds(generics, path, scope, &params);
+                    reference.generalization.extend(supertraits.iter()
+                                                               .filter_map(|bound| if let &syn::TypeParamBound::Trait(syn::TraitBound { path: ref supertrait, .. }) = bound {
+                                                                   resolve(scope, path, supertrait, &params)
+                                                               } else {
+                                                                   None
+                                                               }));
+                },
+                &syn::Item::Struct(syn::ItemStruct { ref ident, ref generics, ref fields, .. }) => {
+                    reference.bounds(generics, path, scope, &params);
+                    reference.fields(fields.iter()
+                                           .enumerate()
+                                           .map(|(index, field)| (field2role(index, field), &field.ty)), ident, path, scope, model, &params);
+                },
+                &syn::Item::Enum(syn::ItemEnum { ref ident, ref generics, ref variants, .. }) => {
+                    reference.bounds(generics, path, scope, &params);
+                    reference.fields(variants.iter()
+                                             .flat_map(|variant| variant.fields.iter().map(move |field| match field.ident {
+                                                 Some(ref ident) => (format!("{}.{}", variant.ident, ident), &field.ty),
+                                                 None => (variant.ident.to_string(), &field.ty),
+                                             })), ident, path, scope, model, &params);
+                },
+                &syn::Item::Union(syn::ItemUnion { ref ident, ref generics, ref fields, .. }) => {
+                    reference.bounds(generics, path, scope, &params);
+                    reference.fields(fields.named.iter()
+                                                 .enumerate()
+                                                 .map(|(index, field)| (field2role(index, field), &field.ty)), ident, path, scope, model, &params);
+                },
+                &syn::Item::Type(syn::ItemType { ref ident, ref generics, ref ty, .. }) => {
+                    reference.bounds(generics, path, scope, &params);
+                    reference.fields(Some((String::new(), &**ty)).into_iter(), ident, path, scope, model, &params);
+                },
+                &syn::Item::Impl(syn::ItemImpl { trait_: None, ref generics, ref items, .. }) => {
+                    reference.bounds(generics, path, scope, &params);
+                    reference.methods(items, path, scope, model, &params);
+                },
+                &syn::Item::Impl(syn::ItemImpl { trait_: Some((_, ref trait_, _)), ref generics, ref items, .. }) => {
+                    reference.realization.extend(resolve(scope, path, trait_, &params));
+                    reference.bounds(generics, path, scope, &params);
+                    reference.methods(items, path, scope, model, &params);
+                },
+                &syn::Item::Fn(syn::ItemFn { ref sig, .. }) => {
+                    reference.signature(sig, path, scope, model, &params);
+                },
+                _ => {},
+            }
+        }
+        reference
+    }
+}
+
+/// The function `item2generics` returns the template's parameters of an item.
+fn item2generics(item: &syn::Item) -> Option<&syn::Generics> {
+    match item {
+        &syn::Item::Trait(syn::ItemTrait { ref generics, .. }) |
+        &syn::Item::Struct(syn::ItemStruct { ref generics, .. }) |
+        &syn::Item::Enum(syn::ItemEnum { ref generics, .. }) |
+        &syn::Item::Union(syn::ItemUnion { ref generics, .. }) |
+        &syn::Item::Type(syn::ItemType { ref generics, .. }) |
+        &syn::Item::Impl(syn::ItemImpl { ref generics, .. }) => Some(generics),
+        _ => None,
+    }
+}
+
+/// The function `generics2params` returns the names of the template's type parameters.
+fn generics2params(generics: &syn::Generics) -> Vec<String> {
+    generics.type_params()
+            .map(|param| param.ident.to_string())
+            .collect::<Vec<String>>()
+}
+
+/// The function `resolve` returns the path of the definition named by `ty` written from the
+/// module `path`, none when `ty` names one of the template's parameters `params` like `T` or `T::Item`.
+fn resolve(scope: &Scope, path: &ModulePath, ty: &syn::Path, params: &[String]) -> Option<ModulePath> {
+    match ty.segments.first() {
+        Some(first) if ty.leading_colon.is_none() && params.contains(&first.ident.to_string()) => None,
+        _ => scope.resolve_path(path, ty),
+    }
+}
+
+/// The function `field2role` returns the role of a field, its name or else its index like `.0`.
+fn field2role(index: usize, field: &syn::Field) -> String {
+    field.ident.as_ref().map_or_else(|| format!(".{}", index), |ident| ident.to_string())
+}
+
+/// The function `ty2paths` returns the paths named by a type with how they are held and
+/// the multiplicities of their containers.
+fn ty2paths<'b>(ty: &'b syn::Type, model: &Model) -> Vec<(Ownership, &'b syn::Path, Multiplicity)> {
+    match ty {
+        &syn::Type::Path(syn::TypePath { qself: Some(syn::QSelf { ref ty, .. }), .. }) => ty2paths(ty, model),
+        &syn::Type::Path(syn::TypePath { qself: None, ref path }) => {
+            let mut paths: Vec<(Ownership, &syn::Path, Multiplicity)> = vec!((Ownership::Owned, path, Multiplicity::default()));
+
+            paths.extend(path2paths(path, model));
+            paths
+        },
+        &syn::Type::Ptr(syn::TypePtr { ref elem, .. }) |
+        &syn::Type::Reference(syn::TypeReference { ref elem, .. }) => {
+            times(Ownership::Shared, &Multiplicity::default(), ty2paths(elem, model))
+        },
+        &syn::Type::Array(syn::TypeArray { ref elem, ref len, .. }) => {
+            times(Ownership::Owned, &Multiplicity::from(expr_to_string(len).as_str()), ty2paths(elem, model))
+        },
+        &syn::Type::Slice(syn::TypeSlice { ref elem, .. }) => {
+            times(Ownership::Owned, &Multiplicity::from("0..*"), ty2paths(elem, model))
+        },
+        &syn::Type::Group(syn::TypeGroup { ref elem, .. }) |
+        &syn::Type::Paren(syn::TypeParen { ref elem, .. }) => ty2paths(elem, model),
+        &syn::Type::Tuple(syn::TypeTuple { ref elems, .. }) => {
+            elems.iter().flat_map(|ty| ty2paths(ty, model)).collect::<Vec<(Ownership, &syn::Path, Multiplicity)>>()
+        },
+        &syn::Type::TraitObject(syn::TypeTraitObject { ref bounds, .. }) |
+        &syn::Type::ImplTrait(syn::TypeImplTrait { ref bounds, .. }) => {
+            bounds.iter()
+                  .filter_map(|bound| if let &syn::TypeParamBound::Trait(syn::TraitBound { ref path, .. }) = bound {
+                      Some(path)
+                  } else {
+                      None
+                  })
+                  .flat_map(|path| Some((Ownership::Owned, path, Multiplicity::default())).into_iter().chain(path2paths(path, model)))
+                  .collect::<Vec<(Ownership, &syn::Path, Multiplicity)>>()
+        },
+        &syn::Type::BareFn(syn::TypeBareFn { ref inputs, ref output, .. }) => {
+            inputs.iter()
+                  .map(|arg| &arg.ty)
+                  .chain(output2ty(output))
+                  .flat_map(|ty| ty2paths(ty, model))
+                  .collect::<Vec<(Ownership, &syn::Path, Multiplicity)>>()
+        },
+        _ => Vec::new(),
+    }
+}
+
+/// The function `path2paths` returns the paths named by the generic arguments of a path,
+/// held through the smart pointer and multiplied by the container which hold them.
+fn path2paths<'b>(path: &'b syn::Path, model: &Model) -> Vec<(Ownership, &'b syn::Path, Multiplicity)> {
+    path.segments.iter()
+                 .flat_map(|segment| {
+                     let name: String = segment.ident.to_string();
+                     let ownership: Ownership = model.ownership(&name).unwrap_or_default();
+                     let multiplicity: Multiplicity = model.multiplicity(&name)
+                                                           .map(Multiplicity::from)
+                                                           .unwrap_or_default();
+                     let tys: Vec<&syn::Type> = match segment.arguments {
+                         syn::PathArguments::AngleBracketed(syn::AngleBracketedGenericArguments { ref args, .. }) => {
+                             args.iter()
+                                 .filter_map(|arg| match arg {
+                                     &syn::GenericArgument::Type(ref ty) |
+                                     &syn::GenericArgument::AssocType(syn::AssocType { ref ty, .. }) => Some(ty),
+                                     _ => None,
+                                 })
+                                 .collect::<Vec<&syn::Type>>()
+                         },
+                         syn::PathArguments::Parenthesized(syn::ParenthesizedGenericArguments { ref inputs, ref output, .. }) => {
+                             inputs.iter().chain(output2ty(output)).collect::<Vec<&syn::Type>>()
+                         },
+                         syn::PathArguments::None => Vec::new(),
+                     };
+
+                     times(ownership, &multiplicity, tys.into_iter()
+                                                        .flat_map(|ty| ty2paths(ty, model))
+                                                        .collect::<Vec<(Ownership, &syn::Path, Multiplicity)>>())
+                 })
+                 .collect::<Vec<(Ownership, &syn::Path, Multiplicity)>>()
+}
+
+/// The function `times` returns the paths held by a wrapper of ownership `ownership` and
+/// multiplicity `multiplicity`; the weakest hold wins.
+fn times<'b>(ownership: Ownership, multiplicity: &Multiplicity, paths: Vec<(Ownership, &'b syn::Path, Multiplicity)>) -> Vec<(Ownership, &'b syn::Path, Multiplicity)> {
+    paths.into_iter()
+         .map(|(inner, path, count)| (cmp::max(ownership, inner), path, multiplicity.times(&count)))
+         .collect::<Vec<(Ownership, &syn::Path, Multiplicity)>>()
+}
+
+/// The function `output2ty` returns the type returned by a signature.
+fn output2ty(output: &syn::ReturnType) -> Option<&syn::Type> {
+    match output {
+        &syn::ReturnType::Default => None,
+        &syn::ReturnType::Type(_, ref ty) => Some(ty),
+    }
+}
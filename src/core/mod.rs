@@ -1,18 +1,22 @@
 //! Core Syntax and definitions.
 
+pub mod print;
 pub mod segment;
 pub mod item;
+pub mod model;
 
 use self::segment::Segment;
 use self::item::{Item, ItemState};
 use self::item::relation::Relation;
+use self::model::Model;
 
-use std::{slice, iter};
 use std::borrow::Cow;
 use std::ops::BitOr;
 use std::rc::Rc;
 
-use ::syntex_syntax::{ptr, ast};
+use ::syn;
+use ::syn::ext::IdentExt;
+use ::syn::parse::Parser;
 use ::dot::{Nodes, Edges, Arrow, Style, GraphWalk, Labeller, LabelText, Id};
 use ::itertools::Itertools;
 
@@ -31,8 +35,14 @@ impl <'a> From<Item<'a>> for ListItem <'a> {
     }
 }
 
-impl <'a> From<iter::Peekable<slice::Iter<'a, (ptr::P<ast::Item>, Rc<ModulePath>)>>> for ListItem <'a> {
-    fn from(list: iter::Peekable<slice::Iter<'a, (ptr::P<ast::Item>, Rc<ModulePath>)>>) -> ListItem <'a> {
+impl <'a> From<&'a [(syn::Item, Rc<ModulePath>)]> for ListItem <'a> {
+    fn from(list: &'a [(syn::Item, Rc<ModulePath>)]) -> ListItem <'a> {
+        ListItem::from(Item::from(list))
+    }
+}
+
+impl <'a, 'b> From<(&'a [(syn::Item, Rc<ModulePath>)], &'b Model)> for ListItem <'a> {
+    fn from(list: (&'a [(syn::Item, Rc<ModulePath>)], &'b Model)) -> ListItem <'a> {
         ListItem::from(Item::from(list))
     }
 }
@@ -45,14 +55,68 @@ impl <'a>Iterator for ListItem<'a> {
     }
 }
 
+impl <'a> ListItem<'a> {
+
+    /// The method `annotations` returns the statements which attach the documentation to the nodes,
+    /// as tooltips and with the feature `notes` as notes.
+    pub fn annotations(&'a self) -> Vec<String> {
+        self.clone()
+            .filter_map(|state| state.as_doc().map(|doc| (self.node_id(&state), doc)))
+            .flat_map(|(id, doc)| {
+                let doc: String = LabelText::LabelStr(doc.into()).to_dot_string();
+                let mut statements: Vec<String> = vec!(format!("{id}[tooltip={doc}];", id = id.as_slice(), doc = doc));
+
+                if cfg!(feature = "notes") {
+                    statements.push(format!("{id}_note[label={doc}][shape=\"note\"];", id = id.as_slice(), doc = doc));
+                    statements.push(format!("{id}_note -> {id}[style=\"dashed\"][arrowhead=\"none\"];", id = id.as_slice()));
+                }
+                statements
+            })
+            .collect::<Vec<String>>()
+    }
+
+    /// The method `ends` returns the edges, written like `ndA -> ndB`, with the attributes
+    /// which write the roles and multiplicities at their ends.
+    pub fn ends(&'a self) -> Vec<(String, String)> {
+        self.edges()
+            .iter()
+            .filter_map(|seg| {
+                let attributes: String = seg.as_tail()
+                                            .map(|tail| format!("[taillabel=<{}>]", tail))
+                                            .into_iter()
+                                            .chain(seg.as_head().map(|head| format!("[headlabel=<{}>]", head)))
+                                            .collect::<String>();
+
+                if attributes.is_empty() {
+                    None
+                } else {
+                    Some((format!("{} -> {}", self.node_id(&seg.left).as_slice(), self.node_id(&seg.right).as_slice()), attributes))
+                }
+            })
+            .collect::<Vec<(String, String)>>()
+    }
+}
+
 impl<'a> Labeller<'a, ItemState<'a>, Segment<'a>> for ListItem<'a> {
     fn graph_id(&'a self) -> Id<'a> {
         Id::new("ml").unwrap()
     }
 
+    /// The method `node_id` returns the name of the element, prefixed by its module's path
+    /// when another module defines a type of the same name, and by `ut` for a utility
+    /// of the free functions instead of `nd`.
     fn node_id(&'a self, state: &ItemState<'a>) -> Id<'a> {
-        match state.as_name() {
-            Some(name) => Id::new(format!("nd{}", name)).unwrap(),
+        let prefix: &str = if state.is_utility() {
+            "ut"
+        } else {
+            "nd"
+        };
+
+        match (state.as_name(), state.as_path()) {
+            (Some(name), _) if self.parse.is_unique(state) => names2id(prefix, &[name]),
+            (_, Some(path)) => names2id(prefix, &path.path.iter()
+                                                  .map(|segment| segment.to_string_lossy())
+                                                  .collect::<Vec<Cow<str>>>()),
             _ => unreachable!(),
         }
     }
@@ -62,7 +126,7 @@ impl<'a> Labeller<'a, ItemState<'a>, Segment<'a>> for ListItem<'a> {
     }
 
     fn node_label(&'a self, state: &ItemState<'a>) -> LabelText<'a> {
-        LabelText::LabelStr(format!("{}", state).into())
+        LabelText::EscStr(format!("{}", state).into())
     }
 
     fn edge_end_arrow(&'a self, ref seg: &Segment<'a>) -> Arrow {
@@ -75,15 +139,41 @@ impl<'a> Labeller<'a, ItemState<'a>, Segment<'a>> for ListItem<'a> {
         }
     }
 
+    /// The method `edge_style` returns the line of the relation drawn by `edge_end_arrow`,
+    /// dashed for a dependency or a realization.
     fn edge_style(&'a self, ref seg: &Segment<'a>) -> Style {
-        if seg.left.is_realization(&seg.right).bitor(seg.left.is_dependency(&seg.right)) {
-            Style::Dashed
-        } else {
-            Style::None
+        match (
+            seg.left.as_arrow(&seg.right),
+            seg.right.as_arrow(&seg.left)
+        ) {
+            (Relation::Association, Relation::Association) => Style::None,
+            (edge_left, _) if edge_left.is_dashed() => Style::Dashed,
+            _ => Style::None,
         }
     }
 }
 
+/// The function `names2id` returns the identifier of a node from the prefix `prefix` and
+/// the names of its path, without their raw prefix `r#`, joined by `_` and with every
+/// character other than `[A-Za-z0-9_]` escaped by its code like `_u00e9`.
+fn names2id<'a, S: AsRef<str>>(prefix: &str, names: &[S]) -> Id<'a> {
+    let id: String = names.iter()
+                          .map(|name| syn::Ident::parse_any.parse_str(name.as_ref())
+                                                           .map(|ident| ident.unraw().to_string())
+                                                           .unwrap_or_else(|_| name.as_ref().to_string()))
+                          .map(|name| name.chars()
+                                          .map(|c| if c.is_ascii_alphanumeric() || c == '_' {
+                                              c.to_string()
+                                          } else {
+                                              format!("_u{:04x}", c as u32)
+                                          })
+                                          .collect::<String>())
+                          .collect::<Vec<String>>()
+                          .join("_");
+
+    Id::new(format!("{}{}", prefix, id)).unwrap_or_else(|()| unreachable!())
+}
+
 impl<'a> GraphWalk<'a, ItemState<'a>, Segment<'a>> for ListItem<'a> {
     fn nodes(&'a self) -> Nodes<'a, ItemState<'a>> {
         Cow::Owned(self.clone().collect::<Vec<ItemState<'a>>>())
@@ -94,7 +184,7 @@ impl<'a> GraphWalk<'a, ItemState<'a>, Segment<'a>> for ListItem<'a> {
 
         Cow::Owned(items.iter()
                         .map(|item| items.iter()
-                                         .filter(|rhs| item.ne(rhs))
+                                         .filter(|rhs| item.ne(rhs).bitor(item.is_reflexive()))
                                          .filter(|rhs| item.is_relation(rhs))
                                          .map(|rhs| Segment::from((item.clone(), rhs.clone())))
                                          .collect::<Vec<Segment<'a>>>())
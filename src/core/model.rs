@@ -0,0 +1,54 @@
+use super::item::state::multiplicity::DEFAULT_CONTAINERS;
+use super::item::state::ownership::{Ownership, DEFAULT_POINTERS};
+
+/// The structure `Model` is the set of the crate's own containers and smart pointers,
+/// which complete the standard ones when the relations are drawn.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Model {
+    /// Names and multiplicities of the containers like `("SmallVec", "0..*")`.
+    pub containers: Vec<(String, String)>,
+    /// Names and ownerships of the smart pointers like `("Gc", Ownership::Shared)`.
+    pub pointers: Vec<(String, Ownership)>,
+}
+
+impl Model {
+
+    /// The constructor method `new` returns a model with only the standard containers
+    /// and smart pointers.
+    pub fn new() -> Model {
+        Model::default()
+    }
+
+    /// The method `container` declares a container of the crate and the multiplicity
+    /// of its elements, written like `0..1`, `0..*`, `1..*` or `4`.
+    pub fn container<S: Into<String>, M: Into<String>>(mut self, name: S, multiplicity: M) -> Model {
+        self.containers.push((name.into(), multiplicity.into()));
+        self
+    }
+
+    /// The method `pointer` declares a smart pointer of the crate and how it holds the pointed type.
+    pub fn pointer<S: Into<String>>(mut self, name: S, ownership: Ownership) -> Model {
+        self.pointers.push((name.into(), ownership));
+        self
+    }
+
+    /// The method `multiplicity` returns the multiplicity of the container named `name`,
+    /// looked for in the crate's own containers and then in the standard ones.
+    pub fn multiplicity(&self, name: &str) -> Option<&str> {
+        self.containers.iter()
+                       .map(|&(ref container, ref multiplicity)| (container.as_str(), multiplicity.as_str()))
+                       .chain(DEFAULT_CONTAINERS.iter().cloned())
+                       .find(|&(container, _)| container.eq(name))
+                       .map(|(_, multiplicity)| multiplicity)
+    }
+
+    /// The method `ownership` returns the ownership of the smart pointer named `name`,
+    /// looked for in the crate's own smart pointers and then in the standard ones.
+    pub fn ownership(&self, name: &str) -> Option<Ownership> {
+        self.pointers.iter()
+                     .map(|&(ref pointer, ownership)| (pointer.as_str(), ownership))
+                     .chain(DEFAULT_POINTERS.iter().cloned())
+                     .find(|&(pointer, _)| pointer.eq(name))
+                     .map(|(_, ownership)| ownership)
+    }
+}
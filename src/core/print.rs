@@ -0,0 +1,354 @@
+//! Pretty printing of the syntax tree.
+
+use ::syn;
+use ::quote::ToTokens;
+
+/// The function `vis_to_marker` returns the UML marker of a visibility: `+` for `pub`,
+/// `~` for the package `pub(crate)`, `#` for the ancestor modules of `pub(super)`
+/// and `pub(in path)`, and `-` for the private or `pub(self)` items.
+pub fn vis_to_marker(vis: &syn::Visibility) -> &'static str {
+    match vis {
+        &syn::Visibility::Public(_) => "+",
+        &syn::Visibility::Restricted(syn::VisRestricted { ref path, .. }) if path.is_ident("crate") => "~",
+        &syn::Visibility::Restricted(syn::VisRestricted { ref path, .. }) if path.is_ident("self") => "-",
+        &syn::Visibility::Restricted(_) => "#",
+        &syn::Visibility::Inherited => "-",
+    }
+}
+
+/// The function `ty_to_string` returns the source's representation of a type.
+pub fn ty_to_string(ty: &syn::Type) -> String {
+    match ty {
+        &syn::Type::Array(syn::TypeArray { ref elem, ref len, .. }) => {
+            format!("[{}; {}]", ty_to_string(elem), expr_to_string(len))
+        },
+        &syn::Type::BareFn(syn::TypeBareFn { ref unsafety, ref abi, ref inputs, ref variadic, ref output, .. }) => {
+            let mut args: Vec<String> = inputs.iter()
+                                               .map(|&syn::BareFnArg { ref name, ref ty, .. }|
+                                                   if let &Some((ref name, _)) = name {
+                                                       format!("{}: {}", name, ty_to_string(ty))
+                                                   } else {
+                                                       ty_to_string(ty)
+                                                   }
+                                               )
+                                               .collect::<Vec<String>>();
+
+            if variadic.is_some() {
+                args.push(String::from("..."));
+            }
+            format!("{unsafety}{abi}fn({args}){output}",
+                unsafety = if unsafety.is_some() { "unsafe " } else { "" },
+                abi = abi.as_ref().map(abi_to_string).unwrap_or_default(),
+                args = args.join(", "),
+                output = output_to_string(output))
+        },
+        &syn::Type::Group(syn::TypeGroup { ref elem, .. }) => ty_to_string(elem),
+        &syn::Type::ImplTrait(syn::TypeImplTrait { ref bounds, .. }) => {
+            format!("impl {}", bounds_to_string(bounds.iter()))
+        },
+        &syn::Type::Infer(_) => String::from("_"),
+        &syn::Type::Macro(syn::TypeMacro { ref mac }) => mac.to_token_stream().to_string(),
+        &syn::Type::Never(_) => String::from("!"),
+        &syn::Type::Paren(syn::TypeParen { ref elem, .. }) => format!("({})", ty_to_string(elem)),
+        &syn::Type::Path(syn::TypePath { ref qself, ref path }) => {
+            if let &Some(syn::QSelf { ref ty, position, .. }) = qself {
+                let segments: Vec<String> = path.segments.iter()
+                                                         .map(segment_to_string)
+                                                         .collect::<Vec<String>>();
+                let (trait_, rest): (&[String], &[String]) = segments.split_at(position);
+
+                if trait_.is_empty() {
+                    format!("<{}>::{}", ty_to_string(ty), rest.join("::"))
+                } else {
+                    format!("<{} as {}>::{}", ty_to_string(ty), trait_.join("::"), rest.join("::"))
+                }
+            } else {
+                path_to_string(path)
+            }
+        },
+        &syn::Type::Ptr(syn::TypePtr { ref mutability, ref elem, .. }) => {
+            if mutability.is_some() {
+                format!("*mut {}", ty_to_string(elem))
+            } else {
+                format!("*const {}", ty_to_string(elem))
+            }
+        },
+        &syn::Type::Reference(syn::TypeReference { ref lifetime, ref mutability, ref elem, .. }) => {
+            format!("&{lifetime}{mutability}{elem}",
+                lifetime = lifetime.as_ref().map(|lifetime| format!("{} ", lifetime)).unwrap_or_default(),
+                mutability = if mutability.is_some() { "mut " } else { "" },
+                elem = ty_to_string(elem))
+        },
+        &syn::Type::Slice(syn::TypeSlice { ref elem, .. }) => format!("[{}]", ty_to_string(elem)),
+        &syn::Type::TraitObject(syn::TypeTraitObject { ref dyn_token, ref bounds }) => {
+            if dyn_token.is_some() {
+                format!("dyn {}", bounds_to_string(bounds.iter()))
+            } else {
+                bounds_to_string(bounds.iter())
+            }
+        },
+        &syn::Type::Tuple(syn::TypeTuple { ref elems, .. }) => {
+            if elems.len() == 1 {
+                format!("({},)", elems.iter().map(ty_to_string).collect::<String>())
+            } else {
+                format!("({})", elems.iter().map(ty_to_string).collect::<Vec<String>>().join(", "))
+            }
+        },
+        ty => ty.to_token_stream().to_string(),
+    }
+}
+
+/// The function `arg_to_string` returns the source's representation of an argument.
+pub fn arg_to_string(arg: &syn::FnArg) -> String {
+    match arg {
+        &syn::FnArg::Receiver(syn::Receiver { ref reference, ref mutability, ref colon_token, ref ty, .. }) => {
+            if colon_token.is_some() {
+                format!("{}self: {}", if mutability.is_some() { "mut " } else { "" }, ty_to_string(ty))
+            } else if let &Some((_, ref lifetime)) = reference {
+                format!("&{lifetime}{mutability}self",
+                    lifetime = lifetime.as_ref().map(|lifetime| format!("{} ", lifetime)).unwrap_or_default(),
+                    mutability = if mutability.is_some() { "mut " } else { "" })
+            } else {
+                format!("{}self", if mutability.is_some() { "mut " } else { "" })
+            }
+        },
+        &syn::FnArg::Typed(syn::PatType { ref pat, ref ty, .. }) => {
+            format!("{}: {}", pat_to_string(pat), ty_to_string(ty))
+        },
+    }
+}
+
+/// The function `arg_to_ty` returns the type of an argument, `Self` for the receivers.
+pub fn arg_to_ty(arg: &syn::FnArg) -> &syn::Type {
+    match arg {
+        &syn::FnArg::Receiver(syn::Receiver { ref ty, .. }) => ty,
+        &syn::FnArg::Typed(syn::PatType { ref ty, .. }) => ty,
+    }
+}
+
+/// The function `pat_to_string` returns the source's representation of a pattern.
+pub fn pat_to_string(pat: &syn::Pat) -> String {
+    match pat {
+        &syn::Pat::Ident(syn::PatIdent { ref by_ref, ref mutability, ref ident, ref subpat, .. }) => {
+            format!("{by_ref}{mutability}{ident}{subpat}",
+                by_ref = if by_ref.is_some() { "ref " } else { "" },
+                mutability = if mutability.is_some() { "mut " } else { "" },
+                ident = ident,
+                subpat = subpat.as_ref().map(|&(_, ref pat)| format!(" @ {}", pat_to_string(pat))).unwrap_or_default())
+        },
+        &syn::Pat::Wild(_) => String::from("_"),
+        &syn::Pat::Tuple(syn::PatTuple { ref elems, .. }) => {
+            format!("({})", elems.iter().map(pat_to_string).collect::<Vec<String>>().join(", "))
+        },
+        &syn::Pat::Reference(syn::PatReference { ref mutability, ref pat, .. }) => {
+            format!("&{}{}", if mutability.is_some() { "mut " } else { "" }, pat_to_string(pat))
+        },
+        pat => pat.to_token_stream().to_string(),
+    }
+}
+
+/// The function `path_to_string` returns the source's representation of a path.
+pub fn path_to_string(path: &syn::Path) -> String {
+    format!("{}{}",
+        if path.leading_colon.is_some() { "::" } else { "" },
+        path.segments.iter()
+                     .map(segment_to_string)
+                     .collect::<Vec<String>>()
+                     .join("::"))
+}
+
+/// The function `segment_to_string` returns the source's representation of a path's segment.
+pub fn segment_to_string(segment: &syn::PathSegment) -> String {
+    match segment.arguments {
+        syn::PathArguments::None => segment.ident.to_string(),
+        syn::PathArguments::AngleBracketed(syn::AngleBracketedGenericArguments { ref args, .. }) => {
+            format!("{}<{}>", segment.ident, args.iter()
+                                                 .map(generic_argument_to_string)
+                                                 .collect::<Vec<String>>()
+                                                 .join(", "))
+        },
+        syn::PathArguments::Parenthesized(syn::ParenthesizedGenericArguments { ref inputs, ref output, .. }) => {
+            format!("{}({}){}", segment.ident, inputs.iter()
+                                                    .map(ty_to_string)
+                                                    .collect::<Vec<String>>()
+                                                    .join(", "),
+                                               output_to_string(output))
+        },
+    }
+}
+
+/// The function `generic_argument_to_string` returns the source's representation of a generic argument.
+pub fn generic_argument_to_string(arg: &syn::GenericArgument) -> String {
+    match arg {
+        &syn::GenericArgument::Lifetime(ref lifetime) => lifetime.to_string(),
+        &syn::GenericArgument::Type(ref ty) => ty_to_string(ty),
+        &syn::GenericArgument::Const(ref expr) => expr_to_string(expr),
+        &syn::GenericArgument::AssocType(syn::AssocType { ref ident, ref ty, .. }) => {
+            format!("{} = {}", ident, ty_to_string(ty))
+        },
+        &syn::GenericArgument::AssocConst(syn::AssocConst { ref ident, ref value, .. }) => {
+            format!("{} = {}", ident, expr_to_string(value))
+        },
+        &syn::GenericArgument::Constraint(syn::Constraint { ref ident, ref bounds, .. }) => {
+            format!("{}: {}", ident, bounds_to_string(bounds.iter()))
+        },
+        arg => arg.to_token_stream().to_string(),
+    }
+}
+
+/// The function `bound_to_string` returns the source's representation of a bound.
+pub fn bound_to_string(bound: &syn::TypeParamBound) -> String {
+    match bound {
+        &syn::TypeParamBound::Trait(syn::TraitBound { ref paren_token, ref modifier, ref lifetimes, ref path }) => {
+            let bound: String = format!("{lifetimes}{modifier}{path}",
+                lifetimes = lifetimes.as_ref()
+                                     .map(|&syn::BoundLifetimes { ref lifetimes, .. }|
+                                         format!("for<{}> ", lifetimes.iter()
+                                                                      .map(|lifetime| lifetime.to_token_stream().to_string())
+                                                                      .collect::<Vec<String>>()
+                                                                      .join(", ")))
+                                     .unwrap_or_default(),
+                modifier = if let &syn::TraitBoundModifier::Maybe(_) = modifier { "?" } else { "" },
+                path = path_to_string(path));
+
+            if paren_token.is_some() {
+                format!("({})", bound)
+            } else {
+                bound
+            }
+        },
+        &syn::TypeParamBound::Lifetime(ref lifetime) => lifetime.to_string(),
+        bound => bound.to_token_stream().to_string(),
+    }
+}
+
+/// The function `bounds_to_string` returns the source's representation of a list of bounds.
+pub fn bounds_to_string<'a, I: Iterator<Item = &'a syn::TypeParamBound>>(bounds: I) -> String {
+    bounds.map(bound_to_string)
+          .collect::<Vec<String>>()
+          .join(" + ")
+}
+
+/// The function `generics_to_string` returns the source's representation of generic parameters,
+/// without the `where` clause.
+pub fn generics_to_string(generics: &syn::Generics) -> String {
+    if generics.params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", generics.params.iter()
+                                       .map(generic_param_to_string)
+                                       .collect::<Vec<String>>()
+                                       .join(", "))
+    }
+}
+
+/// The function `generic_param_to_string` returns the source's representation of a generic parameter.
+pub fn generic_param_to_string(param: &syn::GenericParam) -> String {
+    match param {
+        &syn::GenericParam::Lifetime(syn::LifetimeParam { ref lifetime, ref bounds, .. }) => {
+            if bounds.is_empty() {
+                lifetime.to_string()
+            } else {
+                format!("{}: {}", lifetime, bounds.iter()
+                                                  .map(|bound| bound.to_string())
+                                                  .collect::<Vec<String>>()
+                                                  .join(" + "))
+            }
+        },
+        &syn::GenericParam::Type(syn::TypeParam { ref ident, ref bounds, ref default, .. }) => {
+            format!("{ident}{bounds}{default}",
+                ident = ident,
+                bounds = if bounds.is_empty() { String::new() } else { format!(": {}", bounds_to_string(bounds.iter())) },
+                default = default.as_ref().map(|ty| format!(" = {}", ty_to_string(ty))).unwrap_or_default())
+        },
+        &syn::GenericParam::Const(syn::ConstParam { ref ident, ref ty, ref default, .. }) => {
+            format!("const {ident}: {ty}{default}",
+                ident = ident,
+                ty = ty_to_string(ty),
+                default = default.as_ref().map(|expr| format!(" = {}", expr_to_string(expr))).unwrap_or_default())
+        },
+    }
+}
+
+/// The function `where_predicate_to_string` returns the source's representation of a `where` clause's predicate.
+pub fn where_predicate_to_string(predicate: &syn::WherePredicate) -> String {
+    match predicate {
+        &syn::WherePredicate::Lifetime(syn::PredicateLifetime { ref lifetime, ref bounds, .. }) => {
+            format!("{}: {}", lifetime, bounds.iter()
+                                              .map(|bound| bound.to_string())
+                                              .collect::<Vec<String>>()
+                                              .join(" + "))
+        },
+        &syn::WherePredicate::Type(syn::PredicateType { ref lifetimes, ref bounded_ty, ref bounds, .. }) => {
+            format!("{lifetimes}{ty}: {bounds}",
+                lifetimes = lifetimes.as_ref()
+                                     .map(|&syn::BoundLifetimes { ref lifetimes, .. }|
+                                         format!("for<{}> ", lifetimes.iter()
+                                                                      .map(|lifetime| lifetime.to_token_stream().to_string())
+                                                                      .collect::<Vec<String>>()
+                                                                      .join(", ")))
+                                     .unwrap_or_default(),
+                ty = ty_to_string(bounded_ty),
+                bounds = bounds_to_string(bounds.iter()))
+        },
+        predicate => predicate.to_token_stream().to_string(),
+    }
+}
+
+/// The function `output_to_ty` returns the type returned by a signature,
+/// or else none when it returns nothing or the unit `()`.
+pub fn output_to_ty(output: &syn::ReturnType) -> Option<&syn::Type> {
+    match output {
+        &syn::ReturnType::Type(_, ref ty) => match &**ty {
+            &syn::Type::Tuple(syn::TypeTuple { ref elems, .. }) if elems.is_empty() => None,
+            ty => Some(ty),
+        },
+        &syn::ReturnType::Default => None,
+    }
+}
+
+/// The function `output_to_string` returns the source's representation of a returned type.
+fn output_to_string(output: &syn::ReturnType) -> String {
+    match output {
+        &syn::ReturnType::Default => String::new(),
+        &syn::ReturnType::Type(_, ref ty) => format!(" -> {}", ty_to_string(ty)),
+    }
+}
+
+/// The function `abi_to_string` returns the source's representation of an application binary interface.
+fn abi_to_string(abi: &syn::Abi) -> String {
+    if let Some(ref name) = abi.name {
+        format!("extern \"{}\" ", name.value())
+    } else {
+        String::from("extern ")
+    }
+}
+
+/// The function `expr_to_string` returns the source's representation of an expression.
+pub fn expr_to_string(expr: &syn::Expr) -> String {
+    match expr {
+        &syn::Expr::Lit(syn::ExprLit { ref lit, .. }) => lit.to_token_stream().to_string(),
+        &syn::Expr::Path(syn::ExprPath { ref path, .. }) => path_to_string(path),
+        expr => expr.to_token_stream().to_string(),
+    }
+}
+
+/// The function `meta_to_string` returns the source's representation of an attribute's content
+/// like a configuration predicate.
+pub fn meta_to_string(meta: &syn::Meta) -> String {
+    match meta {
+        &syn::Meta::Path(ref path) => path_to_string(path),
+        &syn::Meta::NameValue(syn::MetaNameValue { ref path, ref value, .. }) => {
+            format!("{} = {}", path_to_string(path), expr_to_string(value))
+        },
+        &syn::Meta::List(ref list) => {
+            match list.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                Ok(nested) => format!("{}({})", path_to_string(&list.path), nested.iter()
+                                                                                  .map(meta_to_string)
+                                                                                  .collect::<Vec<String>>()
+                                                                                  .join(", ")),
+                Err(_) => format!("{}({})", path_to_string(&list.path), list.tokens),
+            }
+        },
+    }
+}
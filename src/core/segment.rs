@@ -1,19 +1,46 @@
 use std::ops::{BitOr, BitAnd};
 use std::hash::{Hash, Hasher};
 
+use ::dot::escape_html;
+
 use super::ItemState;
+use super::item::state::multiplicity::Multiplicity;
 
-/// The structure `Segment` contents two nodes.
+/// The structure `Segment` contents two nodes, with the roles of each node
+/// held by the other; a loop on a node carries its roles once.
 
 #[derive(Debug, Clone, Eq)]
 pub struct Segment<'a> {
     pub left: ItemState<'a>,
     pub right: ItemState<'a>,
+    /// Fields, variants or methods of the right node which hold the left node, with their multiplicities.
+    pub tail: Vec<(String, Multiplicity)>,
+    /// Fields, variants or methods of the left node which hold the right node, with their multiplicities.
+    pub head: Vec<(String, Multiplicity)>,
+}
+
+impl <'a> Segment<'a> {
+
+    /// The method `as_tail` returns the roles of the left node, formated for a html label.
+    pub fn as_tail(&self) -> Option<String> {
+        roles_to_html(&self.tail)
+    }
+
+    /// The method `as_head` returns the roles of the right node, formated for a html label.
+    pub fn as_head(&self) -> Option<String> {
+        roles_to_html(&self.head)
+    }
 }
 
 impl <'a> From<(ItemState<'a>, ItemState<'a>)> for Segment <'a> {
     fn from((left, right): (ItemState<'a>, ItemState<'a>)) -> Segment<'a> {
         Segment {
+            tail: left.as_roles(&right),
+            head: if left.eq(&right) {
+                Vec::new()
+            } else {
+                right.as_roles(&left)
+            },
             left: left,
             right: right,
         }
@@ -33,3 +60,20 @@ impl <'a> PartialEq for Segment <'a> {
                                                    .bitand(self.right.eq(&rhs.left)))
     }
 }
+
+/// The function `roles_to_html` returns the roles followed by their multiplicities,
+/// one by line.
+fn roles_to_html(roles: &[(String, Multiplicity)]) -> Option<String> {
+    if roles.is_empty() {
+        None
+    } else {
+        Some(roles.iter()
+                  .map(|&(ref role, ref multiplicity)| if role.is_empty() {
+                      escape_html(&multiplicity.to_string())
+                  } else {
+                      escape_html(&format!("{} {}", role, multiplicity))
+                  })
+                  .collect::<Vec<String>>()
+                  .join("<br/>"))
+    }
+}
@@ -0,0 +1,123 @@
+//! Errors and diagnostics.
+
+use std::error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitStatus;
+
+use ::proc_macro2::Span;
+
+/// The structure `ParseError` is a diagnostic reported by the parser for a file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    /// Path of the file.
+    pub path: PathBuf,
+    /// Line and column where the span begins.
+    pub span: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl From<(PathBuf, Span, String)> for ParseError {
+    fn from((path, span, message): (PathBuf, Span, String)) -> ParseError {
+        let start = span.start();
+
+        ParseError {
+            path: path,
+            span: Some((start.line, start.column + 1)),
+            message: message,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((line, column)) = self.span {
+            write!(f, "{path}:{line}:{column}: {message}",
+                path = self.path.display(),
+                line = line,
+                column = column,
+                message = self.message)
+        } else {
+            write!(f, "{path}: {message}",
+                path = self.path.display(),
+                message = self.message)
+        }
+    }
+}
+
+impl error::Error for ParseError {
+}
+
+/// The enumeration `Error` is the failure of a graph's generation.
+#[derive(Debug)]
+pub enum Error {
+    /// The input or output has failed.
+    Io(io::Error),
+    /// The file hasn't been parsed, with the diagnostics of the parser.
+    Parse(Vec<ParseError>),
+    /// The program of *Graphviz* hasn't been found.
+    DotNotFound(PathBuf),
+    /// The program of *Graphviz* has failed, with its status and standard error.
+    DotFailed(ExitStatus, String),
+    /// The graph rendered by *dot* hasn't the expected layout, with the missing statement.
+    Render(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &Error::Io(ref why) => write!(f, "{}", why),
+            &Error::Parse(ref diagnostics) => {
+                write!(f, "{}", diagnostics.iter()
+                                           .map(|diagnostic| format!("{}", diagnostic))
+                                           .collect::<Vec<String>>()
+                                           .join("\n"))
+            },
+            &Error::DotNotFound(ref program) => {
+                write!(f, "the program `{}` of Graphviz isn't found", program.display())
+            },
+            &Error::DotFailed(ref status, ref stderr) => {
+                write!(f, "the program of Graphviz has failed ({}): {}", status, stderr)
+            },
+            &Error::Render(ref statement) => {
+                write!(f, "the graph rendered by dot hasn't the statement `{}`", statement)
+            },
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            &Error::Io(ref why) => Some(why),
+            &Error::Parse(ref diagnostics) => match diagnostics.first() {
+                Some(why) => Some(why),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(why: io::Error) -> Error {
+        Error::Io(why)
+    }
+}
+
+impl From<Vec<ParseError>> for Error {
+    fn from(diagnostics: Vec<ParseError>) -> Error {
+        Error::Parse(diagnostics)
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(why: Error) -> io::Error {
+        match why {
+            Error::Io(why) => why,
+            why @ Error::DotNotFound(_) => io::Error::new(io::ErrorKind::NotFound, why),
+            why => io::Error::new(io::ErrorKind::InvalidData, why),
+        }
+    }
+}
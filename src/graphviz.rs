@@ -0,0 +1,79 @@
+//! Graphviz's program.
+
+use std::process::{Command, Stdio, Output};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::ffi::OsString;
+use std::thread;
+
+use error::Error;
+
+/// The default program of *Graphviz*.
+pub const DEFAULT_PROGRAM: &'static str = "dot";
+
+/// The structure `Graphviz` is the command which lays out a *graph/dot* content
+/// into a structured vector graphics.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Graphviz {
+    /// Path of the executable.
+    pub program: PathBuf,
+    /// Extra arguments given after `-Tsvg`.
+    pub args: Vec<OsString>,
+}
+
+impl Graphviz {
+
+    /// The constructor method `new` returns a command running the executable `program`.
+    pub fn new<P: Into<PathBuf>>(program: P) -> Graphviz {
+        Graphviz {
+            program: program.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// The method `arg` adds an argument like `-Gdpi=150`.
+    pub fn arg<S: Into<OsString>>(mut self, arg: S) -> Graphviz {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// The method `layout` selects the layout engine like `neato` or `fdp`.
+    pub fn layout<S: AsRef<str>>(self, engine: S) -> Graphviz {
+        self.arg(format!("-K{}", engine.as_ref()))
+    }
+
+    /// The method `content2svg` returns structured vector graphics content of modules.
+    pub fn content2svg(&self, buf: Vec<u8>) -> Result<Vec<u8>, Error> {
+        let mut child = match Command::new(&self.program).arg("-Tsvg")
+                                                         .args(&self.args)
+                                                         .stdin(Stdio::piped())
+                                                         .stdout(Stdio::piped())
+                                                         .stderr(Stdio::piped())
+                                                         .spawn() {
+            Ok(child) => child,
+            Err(ref why) if why.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::DotNotFound(self.program.clone()))
+            },
+            Err(why) => return Err(Error::Io(why)),
+        };
+        // The content is written from its own thread while stdout and stderr are read,
+        // else both processes block once `dot` fills a pipe before reading all its input.
+        let writer: Option<thread::JoinHandle<io::Result<()>>> = child.stdin.take()
+                                                                     .map(|mut stdin| thread::spawn(move || stdin.write_all(buf.as_slice())));
+        let output: Output = child.wait_with_output()?;
+        let write: io::Result<()> = writer.map_or(Ok(()), |writer|
+            writer.join().unwrap_or_else(|_| Err(io::Error::new(io::ErrorKind::Other, "the writer of the content panicked"))));
+
+        match (output.status.success(), write) {
+            (false, _) => Err(Error::DotFailed(output.status, String::from_utf8_lossy(&output.stderr).into_owned())),
+            (true, Err(why)) => Err(Error::Io(why)),
+            (true, Ok(())) => Ok(output.stdout),
+        }
+    }
+}
+
+impl Default for Graphviz {
+    fn default() -> Graphviz {
+        Graphviz::new(DEFAULT_PROGRAM)
+    }
+}
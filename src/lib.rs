@@ -19,62 +19,127 @@
 
 //! ![uml](ml.svg)
 
-extern crate syntex_syntax;
-extern crate syntex_errors;
+extern crate syn;
+extern crate proc_macro2;
+extern crate quote;
 extern crate itertools;
 extern crate walkdir;
 extern crate dot;
 
 pub mod prelude;
+pub mod error;
+pub mod graphviz;
 pub mod module;
 pub mod core;
+pub mod options;
 
-use std::process::{Command, Stdio};
-use std::io::{self, Write, Read};
-use std::path::Path;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::fs::{self, File};
 use std::ffi::OsStr;
 use std::rc::Rc;
 
-use syntex_errors::emitter::ColorConfig;
-use syntex_errors::Handler;
-
-use syntex_syntax::codemap::{CodeMap, FilePathMapping};
-use syntex_syntax::parse::{self, ParseSess};
-use syntex_syntax::{ast, ptr};
-
 use walkdir::WalkDir;
 use core::ListItem;
-use module::Module;
+use core::model::Model;
+use error::{Error, ParseError};
+use graphviz::Graphviz;
+use module::{Module, Loader};
+use module::cfg::Cfg;
 use module::path::ModulePath;
+use options::Options;
 
 /// The default name of *graph/dot* file.
 pub const DEFAULT_NAME_DOT: &'static str = "ml.dot";
 /// The default name of *image/svg* file.
 pub const DEFAULT_NAME_PNG: &'static str = "ml.svg";
+/// The closing line of a *graph/dot* content.
+const DEFAULT_END_DOT: &'static str = "}\n";
+/// The default names of crate root, by order of preference.
+pub const DEFAULT_NAME_ROOT: [&'static str; 2] = ["lib.rs", "main.rs"];
+
+/// The function `root2modules` returns the modules of a crate, loaded from its root
+/// by following the `mod name;` declarations.
+fn root2modules(path: &Path, loader: &mut Loader) -> Result<Vec<Module>, Error> {
+    loader.file2modules(path, ModulePath::default(), path.parent().unwrap_or(Path::new("")))
+}
 
-/// The function `file2crate` returns a syntex module.
-fn file2crate<P: AsRef<Path>>(path: P) -> io::Result<ast::Crate> {
-    let codemap = Rc::new(CodeMap::new(FilePathMapping::empty()));
-    let tty_handler =
-        Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(codemap.clone()));
-    let parse_session: ParseSess = ParseSess::with_span_handler(tty_handler, codemap.clone());
-    let parse = parse::parse_crate_from_file(path.as_ref(), &parse_session);
-    let ast: ast::Crate = parse.unwrap();
+/// The function `src2modules` returns the modules of a repository, loaded from the crate root
+/// if there is one or else from every `.rs` file. With `lenient`, the files which can't be
+/// parsed are skipped and their diagnostics are returned. With `options`, the inactive items are dropped
+/// and the macros are expanded.
+fn src2modules<P: AsRef<Path>>(path: P, lenient: bool, options: &Options) -> Result<(Vec<Module>, Vec<ParseError>), Error> {
+    let root: Option<PathBuf> = DEFAULT_NAME_ROOT.iter()
+                                                 .map(|name| path.as_ref().join(name))
+                                                 .find(|root| root.is_file());
 
-    Ok(ast)
+    if let Some(root) = root {
+        let mut loader: Loader = Loader::new(true, lenient).options(options);
+
+        root2modules(&root, &mut loader).map(|modules| (modules, loader.diagnostics))
+    } else {
+        let mut loader: Loader = Loader::new(false, lenient).options(options);
+        let mut modules: Vec<Module> = Vec::new();
+
+        for file in WalkDir::new(path).into_iter()
+                                      .filter_map(|entry: Result<walkdir::DirEntry, _>| entry.ok())
+                                      .filter(|entry| entry.file_type().is_file())
+                                      .map(|entry: walkdir::DirEntry| entry.path().to_path_buf())
+                                      .filter(|path: &PathBuf| path.extension().eq(&Some(OsStr::new("rs")))) {
+            modules.extend(loader.file2modules(&file, ModulePath::from(file.as_path()), Path::new(""))?);
+        }
+        Ok((modules, loader.diagnostics))
+    }
 }
 
-/// The function `items2chars` returns a graph formated for *Graphiz/Dot*.
-fn items2chars<'a>(modules: Vec<Module>) -> io::Result<Vec<u8>> {
+/// The function `items2chars` returns a graph formated for *Graphiz/Dot*, with the crate's own
+/// containers and smart pointers of the model `model`.
+fn items2chars<'a>(modules: Vec<Module>, model: &Model) -> Result<Vec<u8>, Error> {
     let mut f: Vec<u8> = Vec::new();
-    let itt: Vec<(ptr::P<ast::Item>, Rc<ModulePath>)> =
+    let itt: Vec<(syn::Item, Rc<ModulePath>)> =
         modules.into_iter()
                .flat_map(|s: Module| s.into_iter())
-               .collect::<Vec<(ptr::P<ast::Item>, Rc<ModulePath>)>>();
-    let it: ListItem = ListItem::from(itt.as_slice().into_iter().peekable());
+               .collect::<Vec<(syn::Item, Rc<ModulePath>)>>();
+    let it: ListItem = ListItem::from((itt.as_slice(), model));
+
+    dot::render(&it, &mut f).map_err(Error::from)?;
+    decorate(f, &it)
+}
+
+/// The function `decorate` writes the attributes and statements unknown from *dot* in the graph
+/// `buf` rendered by *dot*: the labels of the edges' ends after their statements, the tooltips
+/// and notes before the closing brace.
+fn decorate<'a>(buf: Vec<u8>, it: &'a ListItem<'a>) -> Result<Vec<u8>, Error> {
+    let dot: String = String::from_utf8(buf).map_err(|why| io::Error::new(io::ErrorKind::InvalidData, why))?;
+
+    if !dot.ends_with(DEFAULT_END_DOT) {
+        return Err(Error::Render(DEFAULT_END_DOT.trim_end().to_string()));
+    }
+    let mut lines: Vec<String> = dot[..dot.len() - DEFAULT_END_DOT.len()].lines()
+                                                                         .map(String::from)
+                                                                         .collect::<Vec<String>>();
+
+    for (edge, attributes) in it.ends() {
+        let statement: String = format!("{}[", edge);
 
-    dot::render(&it, &mut f).and_then(|()| Ok(f))
+        match lines.iter_mut().find(|line| line.trim_start().starts_with(&statement) && line.ends_with(';')) {
+            Some(line) => {
+                let end: usize = line.len() - 1;
+
+                line.insert_str(end, &attributes);
+            },
+            None => return Err(Error::Render(edge)),
+        }
+    }
+    let mut dot: String = lines.into_iter()
+                               .map(|line| line + "\n")
+                               .collect::<String>();
+
+    for annotation in it.annotations() {
+        dot.push_str(&format!("    {}\n", annotation));
+    }
+    dot.push_str(DEFAULT_END_DOT);
+    Ok(dot.into_bytes())
 }
 
 /// The function `rs2dot` returns graphed file module.
@@ -87,12 +152,67 @@ fn items2chars<'a>(modules: Vec<Module>) -> io::Result<Vec<u8>> {
 ///     let _ = mml::rs2dot("src/lib.rs");
 /// }
 /// ```
-pub fn rs2dot<'a, P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
-    file2crate(path.as_ref()).and_then(|parse: ast::Crate| items2chars(vec![Module::from((parse.module.items, path.as_ref().to_path_buf()))]))
+pub fn rs2dot<'a, P: AsRef<Path>>(path: P) -> Result<Vec<u8>, Error> {
+    rs2dot_with_options(path, &Options::default())
+}
+
+/// The function `rs2dot_with_options` returns graphed file module like `rs2dot`,
+/// loaded and graphed with the options `options`.
+///
+/// # Examples
+/// ```
+/// extern crate mml;
+///
+/// use mml::prelude::{Model, Options};
+///
+/// fn main() {
+///     let _ = mml::rs2dot_with_options("src/lib.rs", &Options::new().model(Model::new().container("SmallVec", "0..*")));
+/// }
+/// ```
+pub fn rs2dot_with_options<P: AsRef<Path>>(path: P, options: &Options) -> Result<Vec<u8>, Error> {
+    Loader::default().options(options)
+                     .file2modules(path.as_ref(), ModulePath::from(path.as_ref()), Path::new(""))
+                     .and_then(|modules| items2chars(modules, &options.model))
+}
+
+/// The function `crate2dot` returns graphed crate of modules, loaded from its root
+/// by following the `mod name;` declarations.
+///
+/// # Examples
+/// ```
+/// extern crate mml;
+///
+/// fn main() {
+///     let _ = mml::crate2dot("src/lib.rs");
+/// }
+/// ```
+pub fn crate2dot<P: AsRef<Path>>(path: P) -> Result<Vec<u8>, Error> {
+    crate2dot_with_options(path, &Options::default())
+}
+
+/// The function `crate2dot_with_options` returns graphed crate of modules like `crate2dot`,
+/// loaded and graphed with the options `options`.
+///
+/// # Examples
+/// ```
+/// extern crate mml;
+///
+/// use mml::prelude::{Model, Options, Ownership};
+///
+/// fn main() {
+///     let _ = mml::crate2dot_with_options("src/lib.rs", &Options::new().model(Model::new().pointer("Gc", Ownership::Shared)));
+/// }
+/// ```
+pub fn crate2dot_with_options<P: AsRef<Path>>(path: P, options: &Options) -> Result<Vec<u8>, Error> {
+    root2modules(path.as_ref(), &mut Loader::new(true, false).options(options)).and_then(|modules| items2chars(modules, &options.model))
 }
 
 /// The function `src2dot` returns graphed repository of modules.
 ///
+/// When the repository holds a crate root (`lib.rs` or else `main.rs`), the modules are
+/// loaded from the module tree like `crate2dot`, and the files which no `mod name;`
+/// declares are skipped; otherwise every `.rs` file is graphed.
+///
 /// # Examples
 /// ```
 /// extern crate mml;
@@ -101,34 +221,84 @@ pub fn rs2dot<'a, P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
 ///     let _ = mml::src2dot("src");
 /// }
 /// ```
-pub fn src2dot<'a, P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
-    items2chars(WalkDir::new(path).into_iter()
-                                 .filter_map(|entry: Result<walkdir::DirEntry, _>| entry.ok())
-                                 .filter(|entry| entry.file_type().is_file())
-                                 .filter_map(|entry: walkdir::DirEntry| {
-                                     let path: &Path = entry.path();
+pub fn src2dot<'a, P: AsRef<Path>>(path: P) -> Result<Vec<u8>, Error> {
+    src2dot_with_options(path, &Options::default())
+}
 
-                                     if path.extension().eq(&Some(OsStr::new("rs"))) {
-                                         file2crate(path).ok().and_then(|parse| Some(Module::from((parse.module.items, path.to_path_buf()))))
-                                     } else {
-                                         None
-                                     }
-                                 })
-                                 .collect::<Vec<Module>>())
+/// The function `src2dot_lenient` returns graphed repository of modules like `src2dot`,
+/// with the files which can't be parsed skipped and their diagnostics.
+///
+/// # Examples
+/// ```
+/// extern crate mml;
+///
+/// fn main() {
+///     if let Ok((_, diagnostics)) = mml::src2dot_lenient("src") {
+///         for diagnostic in diagnostics {
+///             println!("cargo:warning={}", diagnostic);
+///         }
+///     }
+/// }
+/// ```
+pub fn src2dot_lenient<P: AsRef<Path>>(path: P) -> Result<(Vec<u8>, Vec<ParseError>), Error> {
+    src2dot_lenient_with_options(path, &Options::default())
 }
 
-/// The function `content2svg` returns structured vector graphics content of modules.
-fn content2svg(buf: Vec<u8>) -> io::Result<Vec<u8>> {
-        Command::new("dot").arg("-Tsvg")
-                           .stdin(Stdio::piped()).stdout(Stdio::piped())
-                           .spawn()
-                           .and_then(|child| {
-                                let mut ret = vec![];
+/// The function `src2dot_lenient_with_options` returns graphed repository of modules
+/// like `src2dot_lenient`, loaded and graphed with the options `options`.
+///
+/// # Examples
+/// ```
+/// extern crate mml;
+///
+/// use mml::prelude::{Model, Options};
+///
+/// fn main() {
+///     let _ = mml::src2dot_lenient_with_options("src", &Options::new().model(Model::new().container("SmallVec", "0..*")));
+/// }
+/// ```
+pub fn src2dot_lenient_with_options<P: AsRef<Path>>(path: P, options: &Options) -> Result<(Vec<u8>, Vec<ParseError>), Error> {
+    src2modules(path, true, options).and_then(|(modules, diagnostics)|
+        items2chars(modules, &options.model).map(|buf| (buf, diagnostics)))
+}
 
-                                child.stdin.unwrap().write_all(buf.as_slice()).unwrap();
-                                child.stdout.unwrap().read_to_end(&mut ret).unwrap();
-                                Ok(ret)
-                           })
+/// The function `src2dot_with` returns graphed repository of modules like `src2dot`,
+/// without the items disabled by the features and flags of `cfg`.
+///
+/// # Examples
+/// ```
+/// extern crate mml;
+///
+/// use mml::prelude::Cfg;
+///
+/// fn main() {
+///     let _ = mml::src2dot_with("src", &Cfg::new().feature("implem"));
+/// }
+/// ```
+pub fn src2dot_with<P: AsRef<Path>>(path: P, cfg: &Cfg) -> Result<Vec<u8>, Error> {
+    src2dot_with_options(path, &Options::new().cfg(cfg.clone()))
+}
+
+/// The function `src2dot_with_options` returns graphed repository of modules like `src2dot`,
+/// loaded and graphed with the options `options`: the items disabled by its configuration
+/// dropped, the macros expanded and the crate's own containers and smart pointers read
+/// from its model, each one on its own.
+///
+/// # Examples
+/// ```
+/// extern crate mml;
+///
+/// use mml::prelude::{Model, Options, Ownership};
+///
+/// fn main() {
+///     let model: Model = Model::new().container("SmallVec", "0..*")
+///                                    .pointer("Gc", Ownership::Shared);
+///
+///     let _ = mml::src2dot_with_options("src", &Options::new().expand(true).model(model));
+/// }
+/// ```
+pub fn src2dot_with_options<P: AsRef<Path>>(path: P, options: &Options) -> Result<Vec<u8>, Error> {
+    src2modules(path, false, options).and_then(|(modules, _)| items2chars(modules, &options.model))
 }
 
 /// The function `rs2svg` returns structured vector graphics file modules.
@@ -141,8 +311,22 @@ fn content2svg(buf: Vec<u8>) -> io::Result<Vec<u8>> {
 ///     let _ = mml::rs2svg("src/lib.rs");
 /// }
 /// ```
-pub fn rs2svg<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
-    rs2dot(path).and_then(|buf| content2svg(buf))
+pub fn rs2svg<P: AsRef<Path>>(path: P) -> Result<Vec<u8>, Error> {
+    rs2dot(path).and_then(|buf| Graphviz::default().content2svg(buf))
+}
+
+/// The function `crate2svg` returns structured vector graphics crate of modules.
+///
+/// # Examples
+/// ```
+/// extern crate mml;
+///
+/// fn main() {
+///     let _ = mml::crate2svg("src/lib.rs");
+/// }
+/// ```
+pub fn crate2svg<P: AsRef<Path>>(path: P) -> Result<Vec<u8>, Error> {
+    crate2dot(path).and_then(|buf| Graphviz::default().content2svg(buf))
 }
 
 /// The function `src2svg` returns structured vector graphics repository of modules.
@@ -155,8 +339,8 @@ pub fn rs2svg<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
 ///     let _ = mml::src2svg("src");
 /// }
 /// ```
-pub fn src2svg<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
-    src2dot(path).and_then(|buf| content2svg(buf))
+pub fn src2svg<P: AsRef<Path>>(path: P) -> Result<Vec<u8>, Error> {
+    src2dot(path).and_then(|buf| Graphviz::default().content2svg(buf))
 }
 
 /// The function `src2both` creates two files formated like a graph/dot and a structured vector graphics.
@@ -171,7 +355,27 @@ pub fn src2svg<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
 ///    let _ = mml::src2both("src", dest.replace("-", "_").as_str());
 /// }
 /// ```
-pub fn src2both<P: AsRef<Path>>(src: P, dest: P) -> io::Result<()> {
+pub fn src2both<P: AsRef<Path>>(src: P, dest: P) -> Result<(), Error> {
+    src2both_with(src, dest, &Graphviz::default())
+}
+
+/// The function `src2both_with` creates two files like `src2both`, with the structured vector
+/// graphics laid out by a given command of *Graphviz*.
+///
+/// # Examples
+/// ```
+/// extern crate mml;
+///
+/// use mml::graphviz::Graphviz;
+///
+/// fn main() {
+///    let dest: String = concat!("target/doc/", env!("CARGO_PKG_NAME")).to_string();
+///    let graphviz: Graphviz = Graphviz::default().layout("neato").arg("-Gdpi=150");
+///
+///    let _ = mml::src2both_with("src", dest.replace("-", "_").as_str(), &graphviz);
+/// }
+/// ```
+pub fn src2both_with<P: AsRef<Path>>(src: P, dest: P, graphviz: &Graphviz) -> Result<(), Error> {
     let _ = fs::create_dir_all(dest.as_ref())?;
     let mut file_dot = File::create(dest.as_ref().join(DEFAULT_NAME_DOT))?;
     let mut file_svg = File::create(dest.as_ref().join(DEFAULT_NAME_PNG))?;
@@ -179,7 +383,7 @@ pub fn src2both<P: AsRef<Path>>(src: P, dest: P) -> io::Result<()> {
     let content_dot: Vec<u8> = src2dot(src)?;
     let _ = file_dot.write_all(content_dot.as_slice())?;
 
-    let content_svg: Vec<u8> = content2svg(content_dot)?;
+    let content_svg: Vec<u8> = graphviz.content2svg(content_dot)?;
     let _ = file_svg.write_all(content_svg.as_slice())?;
 
     Ok(())
@@ -0,0 +1,242 @@
+use std::mem;
+
+use ::syn;
+use ::syn::punctuated::Punctuated;
+
+/// The structure `Cfg` is the set of enabled features and configuration flags,
+/// against which the `#[cfg(...)]` attributes are evaluated.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Cfg {
+    /// Enabled features like `implem`.
+    pub features: Vec<String>,
+    /// Enabled flags like `test`, or else pairs like `target_os = "linux"`.
+    pub flags: Vec<(String, Option<String>)>,
+    /// Keeps the conditions of the enabled items as `#[doc(cfg(...))]`, shown like stereotypes.
+    pub annotate: bool,
+}
+
+impl Cfg {
+
+    /// The constructor method `new` returns a configuration without feature nor flag.
+    pub fn new() -> Cfg {
+        Cfg::default()
+    }
+
+    /// The method `feature` enables a feature like `implem`.
+    pub fn feature<S: Into<String>>(mut self, feature: S) -> Cfg {
+        self.features.push(feature.into());
+        self
+    }
+
+    /// The method `flag` enables a flag like `test` or `unix`.
+    pub fn flag<S: Into<String>>(mut self, name: S) -> Cfg {
+        self.flags.push((name.into(), None));
+        self
+    }
+
+    /// The method `pair` enables a key-value flag like `target_os = "linux"`.
+    pub fn pair<S: Into<String>, V: Into<String>>(mut self, name: S, value: V) -> Cfg {
+        self.flags.push((name.into(), Some(value.into())));
+        self
+    }
+
+    /// The method `annotate` keeps the conditions of the enabled items.
+    pub fn annotate(mut self, annotate: bool) -> Cfg {
+        self.annotate = annotate;
+        self
+    }
+
+    /// The method `is_active` returns true when the configuration predicate holds.
+    pub fn is_active(&self, predicate: &syn::Meta) -> bool {
+        match predicate {
+            &syn::Meta::Path(ref path) => {
+                path.get_ident().map_or(false, |name| self.flags.contains(&(name.to_string(), None)))
+            },
+            &syn::Meta::NameValue(syn::MetaNameValue {
+                ref path, value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(ref value), .. }), ..
+            }) => {
+                if path.is_ident("feature") {
+                    self.features.contains(&value.value())
+                } else {
+                    path.get_ident().map_or(false, |name| self.flags.contains(&(name.to_string(), Some(value.value()))))
+                }
+            },
+            &syn::Meta::List(ref list) => {
+                let nested: Punctuated<syn::Meta, syn::Token![,]> = list.parse_args_with(Punctuated::parse_terminated)
+                                                                        .unwrap_or_default();
+
+                if list.path.is_ident("all") {
+                    nested.iter().all(|predicate| self.is_active(predicate))
+                } else if list.path.is_ident("any") {
+                    nested.iter().any(|predicate| self.is_active(predicate))
+                } else if list.path.is_ident("not") {
+                    !nested.first().map_or(false, |predicate| self.is_active(predicate))
+                } else {
+                    false
+                }
+            },
+            _ => false,
+        }
+    }
+
+    /// The method `attrs` expands the enabled `#[cfg_attr(...)]` and returns false when
+    /// a `#[cfg(...)]` disables the element.
+    pub fn attrs(&self, attrs: &mut Vec<syn::Attribute>) -> bool {
+        let mut active: bool = true;
+        let mut list: Vec<syn::Attribute> = Vec::new();
+
+        for attr in attrs.drain(..) {
+            if attr.path().is_ident("cfg") {
+                match attr.parse_args::<syn::Meta>() {
+                    Ok(ref predicate) if self.is_active(predicate) => {
+                        if self.annotate {
+                            list.push(syn::parse_quote!(#[doc(cfg(#predicate))]));
+                        }
+                    },
+                    Ok(_) => active = false,
+                    Err(_) => list.push(attr),
+                }
+            } else if attr.path().is_ident("cfg_attr") {
+                match attr.parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated) {
+                    Ok(metas) => {
+                        let mut metas = metas.into_iter();
+
+                        if metas.next().map_or(false, |ref predicate| self.is_active(predicate)) {
+                            let mut expanded: Vec<syn::Attribute> = metas.map(|meta| syn::parse_quote!(#[#meta]))
+                                                                         .collect::<Vec<syn::Attribute>>();
+
+                            active &= self.attrs(&mut expanded);
+                            list.extend(expanded);
+                        }
+                    },
+                    Err(_) => list.push(attr),
+                }
+            } else {
+                list.push(attr);
+            }
+        }
+        *attrs = list;
+        active
+    }
+
+    /// The method `item` removes the disabled members of an item, and returns false when
+    /// the item itself is disabled.
+    pub fn item(&self, item: &mut syn::Item) -> bool {
+        match item {
+            &mut syn::Item::Struct(syn::ItemStruct { ref mut fields, .. }) => self.fields(fields),
+            &mut syn::Item::Union(syn::ItemUnion { fields: syn::FieldsNamed { ref mut named, .. }, .. }) => {
+                self.retain(named, |field| &mut field.attrs);
+            },
+            &mut syn::Item::Enum(syn::ItemEnum { ref mut variants, .. }) => {
+                self.retain(variants, |variant| &mut variant.attrs);
+                for variant in variants.iter_mut() {
+                    self.fields(&mut variant.fields);
+                }
+            },
+            &mut syn::Item::Trait(syn::ItemTrait { ref mut items, .. }) => {
+                *items = mem::replace(items, Vec::new()).into_iter()
+                                                        .filter_map(|mut item| if trait2attrs(&mut item).map_or(true, |attrs| self.attrs(attrs)) {
+                                                            Some(item)
+                                                        } else {
+                                                            None
+                                                        })
+                                                        .collect::<Vec<syn::TraitItem>>();
+            },
+            &mut syn::Item::Impl(syn::ItemImpl { ref mut items, .. }) => {
+                *items = mem::replace(items, Vec::new()).into_iter()
+                                                        .filter_map(|mut item| if impl2attrs(&mut item).map_or(true, |attrs| self.attrs(attrs)) {
+                                                            Some(item)
+                                                        } else {
+                                                            None
+                                                        })
+                                                        .collect::<Vec<syn::ImplItem>>();
+            },
+            &mut syn::Item::ForeignMod(syn::ItemForeignMod { ref mut items, .. }) => {
+                *items = mem::replace(items, Vec::new()).into_iter()
+                                                        .filter_map(|mut item| if foreign2attrs(&mut item).map_or(true, |attrs| self.attrs(attrs)) {
+                                                            Some(item)
+                                                        } else {
+                                                            None
+                                                        })
+                                                        .collect::<Vec<syn::ForeignItem>>();
+            },
+            _ => {},
+        }
+        item2attrs(item).map_or(true, |attrs| self.attrs(attrs))
+    }
+
+    /// The method `fields` removes the disabled fields.
+    fn fields(&self, fields: &mut syn::Fields) {
+        match fields {
+            &mut syn::Fields::Named(syn::FieldsNamed { ref mut named, .. }) => self.retain(named, |field| &mut field.attrs),
+            &mut syn::Fields::Unnamed(syn::FieldsUnnamed { ref mut unnamed, .. }) => self.retain(unnamed, |field| &mut field.attrs),
+            &mut syn::Fields::Unit => {},
+        }
+    }
+
+    /// The method `retain` removes the disabled elements of a punctuated list.
+    fn retain<T, P: Default, F: Fn(&mut T) -> &mut Vec<syn::Attribute>>(&self, list: &mut Punctuated<T, P>, attrs: F) {
+        *list = mem::replace(list, Punctuated::new()).into_iter()
+                                                     .filter_map(|mut element| if self.attrs(attrs(&mut element)) {
+                                                         Some(element)
+                                                     } else {
+                                                         None
+                                                     })
+                                                     .collect::<Punctuated<T, P>>();
+    }
+}
+
+/// The function `item2attrs` returns the attributes of an item.
+fn item2attrs(item: &mut syn::Item) -> Option<&mut Vec<syn::Attribute>> {
+    match item {
+        &mut syn::Item::Const(syn::ItemConst { ref mut attrs, .. }) |
+        &mut syn::Item::Enum(syn::ItemEnum { ref mut attrs, .. }) |
+        &mut syn::Item::ExternCrate(syn::ItemExternCrate { ref mut attrs, .. }) |
+        &mut syn::Item::Fn(syn::ItemFn { ref mut attrs, .. }) |
+        &mut syn::Item::ForeignMod(syn::ItemForeignMod { ref mut attrs, .. }) |
+        &mut syn::Item::Impl(syn::ItemImpl { ref mut attrs, .. }) |
+        &mut syn::Item::Macro(syn::ItemMacro { ref mut attrs, .. }) |
+        &mut syn::Item::Mod(syn::ItemMod { ref mut attrs, .. }) |
+        &mut syn::Item::Static(syn::ItemStatic { ref mut attrs, .. }) |
+        &mut syn::Item::Struct(syn::ItemStruct { ref mut attrs, .. }) |
+        &mut syn::Item::Trait(syn::ItemTrait { ref mut attrs, .. }) |
+        &mut syn::Item::TraitAlias(syn::ItemTraitAlias { ref mut attrs, .. }) |
+        &mut syn::Item::Type(syn::ItemType { ref mut attrs, .. }) |
+        &mut syn::Item::Union(syn::ItemUnion { ref mut attrs, .. }) |
+        &mut syn::Item::Use(syn::ItemUse { ref mut attrs, .. }) => Some(attrs),
+        _ => None,
+    }
+}
+
+/// The function `trait2attrs` returns the attributes of a trait's item.
+fn trait2attrs(item: &mut syn::TraitItem) -> Option<&mut Vec<syn::Attribute>> {
+    match item {
+        &mut syn::TraitItem::Const(syn::TraitItemConst { ref mut attrs, .. }) |
+        &mut syn::TraitItem::Fn(syn::TraitItemFn { ref mut attrs, .. }) |
+        &mut syn::TraitItem::Type(syn::TraitItemType { ref mut attrs, .. }) |
+        &mut syn::TraitItem::Macro(syn::TraitItemMacro { ref mut attrs, .. }) => Some(attrs),
+        _ => None,
+    }
+}
+
+/// The function `impl2attrs` returns the attributes of an implementation's item.
+fn impl2attrs(item: &mut syn::ImplItem) -> Option<&mut Vec<syn::Attribute>> {
+    match item {
+        &mut syn::ImplItem::Const(syn::ImplItemConst { ref mut attrs, .. }) |
+        &mut syn::ImplItem::Fn(syn::ImplItemFn { ref mut attrs, .. }) |
+        &mut syn::ImplItem::Type(syn::ImplItemType { ref mut attrs, .. }) |
+        &mut syn::ImplItem::Macro(syn::ImplItemMacro { ref mut attrs, .. }) => Some(attrs),
+        _ => None,
+    }
+}
+
+/// The function `foreign2attrs` returns the attributes of an extern block's item.
+fn foreign2attrs(item: &mut syn::ForeignItem) -> Option<&mut Vec<syn::Attribute>> {
+    match item {
+        &mut syn::ForeignItem::Fn(syn::ForeignItemFn { ref mut attrs, .. }) |
+        &mut syn::ForeignItem::Static(syn::ForeignItemStatic { ref mut attrs, .. }) |
+        &mut syn::ForeignItem::Type(syn::ForeignItemType { ref mut attrs, .. }) |
+        &mut syn::ForeignItem::Macro(syn::ForeignItemMacro { ref mut attrs, .. }) => Some(attrs),
+        _ => None,
+    }
+}
@@ -0,0 +1,376 @@
+use ::proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
+use ::quote::ToTokens;
+use ::syn;
+use ::syn::ext::IdentExt;
+use ::syn::parse::{ParseStream, Parser};
+use ::syn::parse::discouraged::Speculative;
+
+/// The maximum depth of nested expansions, like the compiler's `recursion_limit`.
+pub const DEFAULT_LIMIT: usize = 128;
+
+/// The enumeration `Matcher` is an element of a rule's pattern.
+#[derive(Debug, Clone)]
+enum Matcher {
+    /// Token matched literally.
+    Token(TokenTree),
+    /// Delimited group of matchers.
+    Group(Delimiter, Vec<Matcher>),
+    /// Metavariable `$name:kind`.
+    Fragment(String, String),
+    /// Repetition `$(...) sep op` with its separator and operator.
+    Repeat(Vec<Matcher>, Option<TokenTree>, char),
+}
+
+/// The enumeration `Fragment` is the tokens bound to a metavariable,
+/// nested once by repetition.
+#[derive(Debug, Clone)]
+enum Fragment {
+    One(TokenStream),
+    Many(Vec<Fragment>),
+}
+
+/// The structure `Expander` is the list of the `macro_rules!` met so far,
+/// which expands the items' invocations of the crate's macros.
+#[derive(Default, Debug, Clone)]
+pub struct Expander {
+    /// Name with the rules of the macros, by order of definition.
+    macros: Vec<(String, Vec<(Vec<Matcher>, TokenStream)>)>,
+}
+
+impl Expander {
+
+    /// The method `define` keeps the macro defined by the item `item`.
+    pub fn define(&mut self, item: &syn::Item) {
+        if let &syn::Item::Macro(syn::ItemMacro { ident: Some(ref name), ref mac, .. }) = item {
+            if mac.path.is_ident("macro_rules") {
+                if let Ok(rules) = Parser::parse2(rules, mac.tokens.clone()) {
+                    self.macros.push((name.to_string(), rules));
+                }
+            }
+        }
+    }
+
+    /// The method `expand` returns the items generated by the invocation `item`,
+    /// or else the implementation `item` with its invocations expanded.
+    pub fn expand(&self, item: &syn::Item) -> Option<Vec<syn::Item>> {
+        match item {
+            &syn::Item::Macro(syn::ItemMacro { ident: None, ref mac, .. }) => {
+                self.invoke(mac).and_then(|tokens| Parser::parse2(parse_all::<syn::Item>, tokens).ok())
+            },
+            &syn::Item::Impl(ref implem) if implem.items.iter().any(|item| self.is_invocation(item)) => {
+                let mut implem: syn::ItemImpl = implem.clone();
+                let mut depth: usize = 0;
+
+                while depth < DEFAULT_LIMIT && implem.items.iter().any(|item| self.is_invocation(item)) {
+                    implem.items = implem.items.into_iter()
+                                               .flat_map(|item| match item {
+                                                   syn::ImplItem::Macro(syn::ImplItemMacro { ref mac, .. }) => {
+                                                       self.invoke(mac)
+                                                           .and_then(|tokens| Parser::parse2(parse_all::<syn::ImplItem>, tokens).ok())
+                                                           .unwrap_or_default()
+                                                   },
+                                                   item => vec!(item),
+                                               })
+                                               .collect::<Vec<syn::ImplItem>>();
+                    depth += 1;
+                }
+                Some(vec!(syn::Item::Impl(implem)))
+            },
+            _ => None,
+        }
+    }
+
+    /// The method `is_invocation` returns true when the implementation's item `item`
+    /// invokes a known macro.
+    fn is_invocation(&self, item: &syn::ImplItem) -> bool {
+        if let &syn::ImplItem::Macro(syn::ImplItemMacro { ref mac, .. }) = item {
+            self.rules(&mac.path).is_some()
+        } else {
+            false
+        }
+    }
+
+    /// The method `rules` returns the rules of the last macro named like the path `path`.
+    fn rules(&self, path: &syn::Path) -> Option<&[(Vec<Matcher>, TokenStream)]> {
+        path.segments.last().and_then(|segment|
+            self.macros.iter()
+                       .rev()
+                       .find(|&&(ref name, _)| segment.ident.eq(name))
+                       .map(|&(_, ref rules)| rules.as_slice()))
+    }
+
+    /// The method `invoke` returns the tokens transcribed by the first rule matching
+    /// the invocation `mac`.
+    fn invoke(&self, mac: &syn::Macro) -> Option<TokenStream> {
+        self.rules(&mac.path).and_then(|rules|
+            rules.iter()
+                 .filter_map(|&(ref matchers, ref transcriber)| {
+                     let mut bindings: Vec<(String, Fragment)> = Vec::new();
+
+                     Parser::parse2(|input: ParseStream| matches(input, matchers, &mut bindings), mac.tokens.clone())
+                            .ok()
+                            .map(|()| transcribe(transcriber.clone(), &bindings))
+                 })
+                 .next())
+    }
+}
+
+/// The function `parse_all` parses a list of items until the end of the input.
+fn parse_all<T: syn::parse::Parse>(input: ParseStream) -> syn::Result<Vec<T>> {
+    let mut items: Vec<T> = Vec::new();
+
+    while !input.is_empty() {
+        items.push(input.parse()?);
+    }
+    Ok(items)
+}
+
+/// The function `rules` parses the rules `(matcher) => { transcriber };` of a `macro_rules!`.
+fn rules(input: ParseStream) -> syn::Result<Vec<(Vec<Matcher>, TokenStream)>> {
+    let mut rules: Vec<(Vec<Matcher>, TokenStream)> = Vec::new();
+
+    while !input.is_empty() {
+        let matcher: Group = input.parse()?;
+        input.parse::<syn::Token![=>]>()?;
+        let transcriber: Group = input.parse()?;
+
+        rules.push((matchers(matcher.stream()), transcriber.stream()));
+        if !input.is_empty() {
+            input.parse::<syn::Token![;]>()?;
+        }
+    }
+    Ok(rules)
+}
+
+/// The function `matchers` returns the pattern of a rule.
+fn matchers(tokens: TokenStream) -> Vec<Matcher> {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect::<Vec<TokenTree>>();
+    let mut matchers: Vec<Matcher> = Vec::new();
+    let mut index: usize = 0;
+
+    while index < tokens.len() {
+        match (&tokens[index], tokens.get(index + 1)) {
+            (&TokenTree::Punct(ref dollar), Some(&TokenTree::Ident(ref name))) if dollar.as_char() == '$' => {
+                match (tokens.get(index + 2), tokens.get(index + 3)) {
+                    (Some(&TokenTree::Punct(ref colon)), Some(&TokenTree::Ident(ref kind))) if colon.as_char() == ':' => {
+                        matchers.push(Matcher::Fragment(name.to_string(), kind.to_string()));
+                        index += 4;
+                    },
+                    _ => {
+                        matchers.push(Matcher::Token(tokens[index + 1].clone()));
+                        index += 2;
+                    },
+                }
+            },
+            (&TokenTree::Punct(ref dollar), Some(&TokenTree::Group(ref group))) if dollar.as_char() == '$' && group.delimiter() == Delimiter::Parenthesis => {
+                let (separator, operator, next): (Option<TokenTree>, char, usize) = repetition(&tokens, index + 2);
+
+                matchers.push(Matcher::Repeat(self::matchers(group.stream()), separator, operator));
+                index = next;
+            },
+            (&TokenTree::Group(ref group), _) => {
+                matchers.push(Matcher::Group(group.delimiter(), self::matchers(group.stream())));
+                index += 1;
+            },
+            (token, _) => {
+                matchers.push(Matcher::Token(token.clone()));
+                index += 1;
+            },
+        }
+    }
+    matchers
+}
+
+/// The function `repetition` returns the separator and operator of a repetition written
+/// from `index`, followed by the index of the next token.
+fn repetition(tokens: &[TokenTree], index: usize) -> (Option<TokenTree>, char, usize) {
+    match (tokens.get(index), tokens.get(index + 1)) {
+        (Some(&TokenTree::Punct(ref operator)), _) if "*+?".contains(operator.as_char()) => {
+            (None, operator.as_char(), index + 1)
+        },
+        (Some(separator), Some(&TokenTree::Punct(ref operator))) if "*+".contains(operator.as_char()) => {
+            (Some(separator.clone()), operator.as_char(), index + 2)
+        },
+        _ => (None, '*', index),
+    }
+}
+
+/// The function `is_same` returns true when two tokens are written alike.
+fn is_same(lhs: &TokenTree, rhs: &TokenTree) -> bool {
+    match (lhs, rhs) {
+        (&TokenTree::Punct(ref lhs), &TokenTree::Punct(ref rhs)) => lhs.as_char() == rhs.as_char(),
+        (&TokenTree::Ident(ref lhs), &TokenTree::Ident(ref rhs)) => lhs.eq(rhs),
+        (&TokenTree::Literal(ref lhs), &TokenTree::Literal(ref rhs)) => lhs.to_string().eq(&rhs.to_string()),
+        _ => false,
+    }
+}
+
+/// The function `matches` binds the metavariables of `matchers` to the tokens of `input`.
+fn matches(input: ParseStream, matchers: &[Matcher], bindings: &mut Vec<(String, Fragment)>) -> syn::Result<()> {
+    for matcher in matchers.iter() {
+        match matcher {
+            &Matcher::Token(ref token) => {
+                let found: TokenTree = input.parse()?;
+
+                if !is_same(token, &found) {
+                    return Err(syn::Error::new(found.span(), format!("expected `{}`", token)));
+                }
+            },
+            &Matcher::Group(delimiter, ref matchers) => {
+                match input.parse::<TokenTree>()? {
+                    TokenTree::Group(ref group) if group.delimiter() == delimiter => {
+                        Parser::parse2(|input: ParseStream| matches(input, matchers, bindings), group.stream())?;
+                    },
+                    found => return Err(syn::Error::new(found.span(), "expected a group")),
+                }
+            },
+            &Matcher::Fragment(ref name, ref kind) => {
+                bindings.push((name.clone(), Fragment::One(fragment(input, kind)?)));
+            },
+            &Matcher::Repeat(ref matchers, ref separator, operator) => {
+                let mut iterations: Vec<Vec<(String, Fragment)>> = Vec::new();
+
+                while !input.is_empty() && !(operator == '?' && iterations.len() == 1) {
+                    let fork = input.fork();
+                    let mut iteration: Vec<(String, Fragment)> = Vec::new();
+
+                    if let (false, &Some(ref separator)) = (iterations.is_empty(), separator) {
+                        match fork.parse::<TokenTree>() {
+                            Ok(ref found) if is_same(separator, found) => {},
+                            _ => break,
+                        }
+                    }
+                    if matches(&fork, matchers, &mut iteration).is_err() || fork.cursor() == input.cursor() {
+                        break;
+                    }
+                    input.advance_to(&fork);
+                    iterations.push(iteration);
+                }
+                if operator == '+' && iterations.is_empty() {
+                    return Err(input.error("expected a repetition"));
+                }
+                for name in names(matchers).into_iter() {
+                    let fragments: Vec<Fragment> = iterations.iter()
+                                                             .filter_map(|iteration| lookup(iteration, &name).cloned())
+                                                             .collect::<Vec<Fragment>>();
+
+                    bindings.push((name, Fragment::Many(fragments)));
+                }
+            },
+        }
+    }
+    Ok(())
+}
+
+/// The function `fragment` returns the tokens of a metavariable of kind `kind`.
+fn fragment(input: ParseStream, kind: &str) -> syn::Result<TokenStream> {
+    match kind {
+        "ident" => input.call(Ident::parse_any).map(ToTokens::into_token_stream),
+        "lifetime" => input.parse::<syn::Lifetime>().map(ToTokens::into_token_stream),
+        "literal" => input.parse::<syn::Lit>().map(ToTokens::into_token_stream),
+        "tt" => input.parse::<TokenTree>().map(ToTokens::into_token_stream),
+        "ty" => input.parse::<syn::Type>().map(ToTokens::into_token_stream),
+        "path" => input.parse::<syn::Path>().map(ToTokens::into_token_stream),
+        "vis" => input.parse::<syn::Visibility>().map(ToTokens::into_token_stream),
+        "meta" => input.parse::<syn::Meta>().map(ToTokens::into_token_stream),
+        "block" => input.parse::<syn::Block>().map(ToTokens::into_token_stream),
+        "item" => input.parse::<syn::Item>().map(ToTokens::into_token_stream),
+        "stmt" => input.parse::<syn::Stmt>().map(ToTokens::into_token_stream),
+        "pat" => input.call(syn::Pat::parse_multi_with_leading_vert).map(ToTokens::into_token_stream),
+        "pat_param" => input.call(syn::Pat::parse_single).map(ToTokens::into_token_stream),
+        "expr" => input.parse::<syn::Expr>().map(|expr|
+            TokenTree::Group(Group::new(Delimiter::None, expr.into_token_stream())).into_token_stream()),
+        kind => Err(input.error(format!("unknown fragment `{}`", kind))),
+    }
+}
+
+/// The function `names` returns the metavariables of a pattern.
+fn names(matchers: &[Matcher]) -> Vec<String> {
+    matchers.iter()
+            .flat_map(|matcher| match matcher {
+                &Matcher::Fragment(ref name, _) => vec!(name.clone()),
+                &Matcher::Group(_, ref matchers) |
+                &Matcher::Repeat(ref matchers, _, _) => names(matchers),
+                &Matcher::Token(_) => Vec::new(),
+            })
+            .collect::<Vec<String>>()
+}
+
+/// The function `lookup` returns the fragment bound to the metavariable `name`.
+fn lookup<'a>(bindings: &'a [(String, Fragment)], name: &str) -> Option<&'a Fragment> {
+    bindings.iter()
+            .rev()
+            .find(|&&(ref bound, _)| bound.eq(name))
+            .map(|&(_, ref fragment)| fragment)
+}
+
+/// The function `transcribe` returns the tokens of a transcriber with its metavariables
+/// replaced by their fragments.
+fn transcribe(tokens: TokenStream, bindings: &[(String, Fragment)]) -> TokenStream {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect::<Vec<TokenTree>>();
+    let mut stream: TokenStream = TokenStream::new();
+    let mut index: usize = 0;
+
+    while index < tokens.len() {
+        match (&tokens[index], tokens.get(index + 1)) {
+            (&TokenTree::Punct(ref dollar), Some(&TokenTree::Ident(ref name))) if dollar.as_char() == '$' => {
+                match lookup(bindings, &name.to_string()) {
+                    Some(&Fragment::One(ref fragment)) => stream.extend(fragment.clone()),
+                    _ if name.eq("crate") => stream.extend(Some(TokenTree::Ident(Ident::new("crate", name.span())))),
+                    _ => stream.extend(tokens[index..index + 2].iter().cloned()),
+                }
+                index += 2;
+            },
+            (&TokenTree::Punct(ref dollar), Some(&TokenTree::Group(ref group))) if dollar.as_char() == '$' && group.delimiter() == Delimiter::Parenthesis => {
+                let (separator, _, next): (Option<TokenTree>, char, usize) = repetition(&tokens, index + 2);
+                let repeated: Vec<(&String, &Vec<Fragment>)> = bindings.iter()
+                                                                      .filter_map(|&(ref name, ref fragment)| match fragment {
+                                                                          &Fragment::Many(ref fragments) => Some((name, fragments)),
+                                                                          &Fragment::One(_) => None,
+                                                                      })
+                                                                      .filter(|&(name, _)| uses(group.stream(), name))
+                                                                      .collect::<Vec<(&String, &Vec<Fragment>)>>();
+                let count: usize = repeated.iter().map(|&(_, fragments)| fragments.len()).min().unwrap_or_default();
+
+                for iteration in 0..count {
+                    let mut nested: Vec<(String, Fragment)> = bindings.to_vec();
+
+                    if iteration > 0 {
+                        stream.extend(separator.clone());
+                    }
+                    nested.extend(repeated.iter().map(|&(name, fragments)| (name.clone(), fragments[iteration].clone())));
+                    stream.extend(transcribe(group.stream(), &nested));
+                }
+                index = next;
+            },
+            (&TokenTree::Group(ref group), _) => {
+                let mut transcribed: Group = Group::new(group.delimiter(), transcribe(group.stream(), bindings));
+
+                transcribed.set_span(group.span());
+                stream.extend(Some(TokenTree::Group(transcribed)));
+                index += 1;
+            },
+            (token, _) => {
+                stream.extend(Some(token.clone()));
+                index += 1;
+            },
+        }
+    }
+    stream
+}
+
+/// The function `uses` returns true when the tokens `tokens` write the metavariable `name`.
+fn uses(tokens: TokenStream, name: &str) -> bool {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect::<Vec<TokenTree>>();
+
+    tokens.iter()
+          .zip(tokens.iter().skip(1))
+          .any(|pair| match pair {
+              (&TokenTree::Punct(ref dollar), &TokenTree::Ident(ref ident)) => dollar.as_char() == '$' && ident.eq(name),
+              _ => false,
+          })
+        || tokens.iter().any(|token| match token {
+              &TokenTree::Group(ref group) => uses(group.stream(), name),
+              _ => false,
+          })
+}
@@ -1,44 +1,238 @@
 use std::rc::Rc;
-use std::path::PathBuf;
-use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::fs;
 use std::vec;
 
-use syntex_syntax::{ast, ptr};
+use ::syn;
+use ::syn::spanned::Spanned;
 
+use ::error::{Error, ParseError};
+use ::options::Options;
+
+pub mod cfg;
+pub mod expand;
 pub mod path;
+pub mod scope;
 
+use self::cfg::Cfg;
+use self::expand::{Expander, DEFAULT_LIMIT};
 use self::path::ModulePath;
 
+/// The default name of module's file which owns its directory.
+const DEFAULT_NAME_MOD: &'static str = "mod.rs";
+
 #[derive(Default, Debug, Clone)]
 pub struct Module {
-    pub list: Vec<ptr::P<ast::Item>>,
+    pub list: Vec<syn::Item>,
     pub path: ModulePath,
 }
 
-impl From<(Vec<ptr::P<ast::Item>>, PathBuf)> for Module {
-    fn from((list, mut path): (Vec<ptr::P<ast::Item>>, PathBuf)) -> Module {
-        path.set_extension("");
+impl From<(Vec<syn::Item>, ModulePath)> for Module {
+    fn from((list, path): (Vec<syn::Item>, ModulePath)) -> Module {
         Module {
             list: list,
-            path: ModulePath {
-                path: path.components()
-                          .skip(1)
-                          .map(|comp| comp.as_os_str().to_os_string())
-                          .collect::<Vec<OsString>>(),
-            },
+            path: path,
         }
     }
 }
 
 impl IntoIterator for Module {
-    type Item = (ptr::P<ast::Item>, Rc<ModulePath>);
-    type IntoIter = vec::IntoIter<(ptr::P<ast::Item>, Rc<ModulePath>)>;
+    type Item = (syn::Item, Rc<ModulePath>);
+    type IntoIter = vec::IntoIter<(syn::Item, Rc<ModulePath>)>;
 
     fn into_iter(self) -> Self::IntoIter {
         let ref rc: Rc<ModulePath> = Rc::new(self.path);
         self.list.into_iter()
                  .map(|item| (item, Rc::clone(rc)))
-                 .collect::<Vec<(ptr::P<ast::Item>, Rc<ModulePath>)>>()
+                 .collect::<Vec<(syn::Item, Rc<ModulePath>)>>()
                  .into_iter()
     }
 }
+
+/// The structure `Loader` loads a file's module followed by its submodules,
+/// written as `mod name { ... }` blocks or declared with `mod name;`.
+#[derive(Default, Debug, Clone)]
+pub struct Loader {
+    /// Follows the `mod name;` declarations to their files.
+    pub follow: bool,
+    /// Skips the files which can't be parsed, and keeps their diagnostics.
+    pub lenient: bool,
+    /// Diagnostics of the skipped files.
+    pub diagnostics: Vec<ParseError>,
+    /// Enabled features and flags, which drop the inactive items when set.
+    pub cfg: Option<Cfg>,
+    /// Expands the invocations of the crate's `macro_rules!`.
+    pub expand: bool,
+    /// Macros met so far, which expand the next items.
+    pub expander: Expander,
+}
+
+impl Loader {
+
+    /// The constructor method `new` returns a loader which follows the `mod name;`
+    /// declarations with `follow` and skips the unparsed files with `lenient`.
+    pub fn new(follow: bool, lenient: bool) -> Loader {
+        Loader {
+            follow: follow,
+            lenient: lenient,
+            diagnostics: Vec::new(),
+            cfg: None,
+            expand: false,
+            expander: Expander::default(),
+        }
+    }
+
+    /// The method `options` drops the inactive items and expands the macros like the options `options`.
+    pub fn options(mut self, options: &Options) -> Loader {
+        self.cfg = options.cfg.clone();
+        self.expand = options.expand;
+        self
+    }
+
+    /// The method `file2modules` returns the module of the file `file` followed by its submodules,
+    /// whose files are looked for from the directory `dir`.
+    pub fn file2modules(&mut self, file: &Path, path: ModulePath, dir: &Path) -> Result<Vec<Module>, Error> {
+        match file2crate(file) {
+            Ok(parse) => self.items2modules(parse.items, path, file, dir, false),
+            Err(Error::Parse(ref diagnostics)) if self.lenient => {
+                self.diagnostics.extend(diagnostics.iter().cloned());
+                Ok(Vec::new())
+            },
+            Err(why) => Err(why),
+        }
+    }
+
+    /// The method `items2modules` returns the module of `items` followed by its submodules,
+    /// with the inactive items dropped and the macros expanded in the order of the source.
+    fn items2modules(&mut self, items: Vec<syn::Item>, path: ModulePath, file: &Path, dir: &Path, inline: bool) -> Result<Vec<Module>, Error> {
+        let mut modules: Vec<Module> = Vec::new();
+        let mut list: Vec<syn::Item> = Vec::new();
+        let mut stack: Vec<(syn::Item, usize)> = items.into_iter()
+                                                      .rev()
+                                                      .map(|item| (item, 0))
+                                                      .collect::<Vec<(syn::Item, usize)>>();
+
+        while let Some((mut item, depth)) = stack.pop() {
+            if let Some(ref cfg) = self.cfg {
+                if !cfg.item(&mut item) {
+                    continue;
+                }
+            }
+            if self.expand {
+                self.expander.define(&item);
+                if depth < DEFAULT_LIMIT {
+                    if let Some(expansion) = self.expander.expand(&item) {
+                        stack.extend(expansion.into_iter()
+                                              .rev()
+                                              .map(|item| (item, depth + 1)));
+                        continue;
+                    }
+                }
+            }
+            if let syn::Item::Mod(ref module) = item {
+                let name: String = module.ident.to_string();
+
+                match module.content {
+                    Some((_, ref content)) => {
+                        modules.extend(self.items2modules(content.clone(), path.join(&name), file, &dir.join(&name), true)?);
+                    },
+                    None if self.follow => {
+                        if let Some(attr) = mod2unliteral(module) {
+                            self.diagnostics.push(ParseError::from((file.to_path_buf(),
+                                                                    attr.span(),
+                                                                    format!("path of module `{}` isn't a string literal", name))));
+                            list.push(item);
+                            continue;
+                        }
+                        match mod2file(module, file, dir, inline) {
+                            Some((ref submodule, ref subdir)) => {
+                                modules.extend(self.file2modules(submodule, path.join(&name), subdir)?);
+                            },
+                            None => {
+                                let diagnostic: ParseError = ParseError::from((file.to_path_buf(),
+                                                                               module.ident.span(),
+                                                                               format!("file not found for module `{}`", name)));
+
+                                if self.lenient {
+                                    self.diagnostics.push(diagnostic);
+                                } else {
+                                    return Err(Error::Parse(vec!(diagnostic)));
+                                }
+                            },
+                        }
+                    },
+                    None => {},
+                }
+            }
+            list.push(item);
+        }
+        modules.insert(0, Module::from((list, path)));
+        Ok(modules)
+    }
+}
+
+/// The function `file2crate` returns the syntax tree of a file or else the diagnostics of the parser.
+fn file2crate<P: AsRef<Path>>(path: P) -> Result<syn::File, Error> {
+    let content: String = fs::read_to_string(path.as_ref())?;
+
+    syn::parse_file(content.as_str()).map_err(|why: syn::Error|
+        Error::Parse(why.into_iter()
+                        .map(|why: syn::Error| ParseError::from((path.as_ref().to_path_buf(), why.span(), why.to_string())))
+                        .collect::<Vec<ParseError>>()))
+}
+
+/// The function `mod2unliteral` returns the attribute `#[path]` of a module declared with `mod name;`
+/// whose value isn't a string literal, like `concat!(env!("OUT_DIR"), "/name.rs")`.
+fn mod2unliteral(module: &syn::ItemMod) -> Option<&syn::Attribute> {
+    module.attrs.iter()
+                .filter(|attr| attr.path().is_ident("path"))
+                .find(|attr| match attr.meta {
+                    syn::Meta::NameValue(syn::MetaNameValue { value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(_), .. }), .. }) => false,
+                    _ => true,
+                })
+}
+
+/// The function `mod2file` returns the existing file of the module declared with `mod name;`
+/// followed by the directory of its submodules' files.
+fn mod2file(module: &syn::ItemMod, file: &Path, dir: &Path, inline: bool) -> Option<(PathBuf, PathBuf)> {
+    let name: String = module.ident.to_string();
+
+    if let Some(path) = module.attrs.iter()
+                                    .filter(|attr| attr.path().is_ident("path"))
+                                    .filter_map(|attr| attr.meta.require_name_value().ok())
+                                    .filter_map(|meta|
+                                        if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(ref path), .. }) = meta.value {
+                                            Some(path.value())
+                                        } else {
+                                            None
+                                        }
+                                    )
+                                    .next() {
+        let submodule: PathBuf = if inline {
+            dir.join(path)
+        } else {
+            file.parent().unwrap_or(dir).join(path)
+        };
+        let subdir: PathBuf = submodule.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        if submodule.is_file() {
+            Some((submodule, subdir))
+        } else {
+            None
+        }
+    } else {
+        let submodule: PathBuf = dir.join(&name).with_extension("rs");
+
+        if submodule.is_file() {
+            Some((submodule, dir.join(&name)))
+        } else {
+            let submodule: PathBuf = dir.join(&name).join(DEFAULT_NAME_MOD);
+
+            if submodule.is_file() {
+                Some((submodule, dir.join(&name)))
+            } else {
+                None
+            }
+        }
+    }
+}
@@ -1,6 +1,67 @@
 use std::ffi::OsString;
+use std::path::Path;
+
+use super::DEFAULT_NAME_MOD;
 
 #[derive(Default, Debug, Clone, Eq, PartialEq)]
 pub struct ModulePath {
     pub path: Vec<OsString>,
 }
+
+impl ModulePath {
+
+    /// The method `join` returns the path of a child module named `name`.
+    pub fn join<S: Into<OsString>>(&self, name: S) -> ModulePath {
+        let mut path: Vec<OsString> = self.path.clone();
+
+        path.push(name.into());
+        ModulePath {
+            path: path,
+        }
+    }
+
+    /// The method `resolve` returns the paths which may be named by the module's `segments`
+    /// written from this module: from `crate`, `self` or `super`, or else from this module
+    /// and from the crate root.
+    pub fn resolve(&self, segments: &[String]) -> Vec<ModulePath> {
+        match segments.split_first() {
+            None => vec!(self.clone()),
+            Some((first, rest)) if first.eq("crate") => vec!(ModulePath::default().extend(rest)),
+            Some((first, rest)) if first.eq("self") => vec!(self.extend(rest)),
+            Some((first, _)) if first.eq("super") => {
+                let supers: usize = segments.iter().take_while(|segment| segment.as_str().eq("super")).count();
+                let mut path: Vec<OsString> = self.path.clone();
+
+                path.truncate(self.path.len().saturating_sub(supers));
+                vec!(ModulePath { path: path }.extend(&segments[supers..]))
+            },
+            Some(_) => vec!(self.extend(segments), ModulePath::default().extend(segments)),
+        }
+    }
+
+    /// The method `extend` returns the path of a descendant module.
+    fn extend(&self, segments: &[String]) -> ModulePath {
+        segments.iter().fold(self.clone(), |path, segment| path.join(segment))
+    }
+}
+
+impl <'a> From<&'a Path> for ModulePath {
+
+    /// The constructor method `from` returns the path of a module from its file,
+    /// without the first directory nor the `mod.rs` file's name.
+    fn from(path: &'a Path) -> ModulePath {
+        let path: &Path = if path.ends_with(DEFAULT_NAME_MOD) {
+            path.parent().unwrap_or(path)
+        } else {
+            path
+        };
+
+        ModulePath {
+            path: path.with_extension("")
+                      .components()
+                      .skip(1)
+                      .map(|comp| comp.as_os_str().to_os_string())
+                      .collect::<Vec<OsString>>(),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::rc::Rc;
+
+use ::syn;
+
+use super::path::ModulePath;
+
+/// The maximum number of imports followed to resolve a path.
+const DEFAULT_DEPTH: usize = 8;
+
+/// The structure `Scope` is the list of definitions and imports of the modules,
+/// which resolves the paths written from a module.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Scope<'a> {
+    /// Paths of the types' definitions with their items.
+    definitions: Vec<(ModulePath, &'a syn::Item)>,
+    /// Module, name or else glob, path of the imports.
+    imports: Vec<(Rc<ModulePath>, Option<String>, Vec<String>)>,
+}
+
+impl <'a> Scope<'a> {
+
+    /// The method `duplicates` returns the names given to more than one definition.
+    pub fn duplicates(&self) -> HashSet<String> {
+        let mut names: HashSet<&OsString> = HashSet::new();
+
+        self.definitions.iter()
+                        .filter_map(|&(ref path, _)| path.path.last())
+                        .filter(|name| !names.insert(name))
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .collect::<HashSet<String>>()
+    }
+
+    /// The method `definition` returns the item which defines the type of path `path`.
+    pub fn definition(&self, path: &ModulePath) -> Option<&'a syn::Item> {
+        self.definitions.iter()
+                        .find(|&&(ref definition, _)| definition.eq(path))
+                        .map(|&(_, item)| item)
+    }
+
+    /// The method `resolve` returns the path of the definition named by `segments`
+    /// written from the module `module`.
+    pub fn resolve(&self, module: &ModulePath, segments: &[String]) -> Option<ModulePath> {
+        self.resolve_depth(module, segments, DEFAULT_DEPTH)
+    }
+
+    /// The method `resolve_path` returns the path of the definition named by a path
+    /// written from the module `module`.
+    pub fn resolve_path(&self, module: &ModulePath, path: &syn::Path) -> Option<ModulePath> {
+        let mut segments: Vec<String> = path.segments.iter()
+                                                     .map(|segment| segment.ident.to_string())
+                                                     .collect::<Vec<String>>();
+
+        if path.leading_colon.is_some() {
+            segments.insert(0, String::from("crate"));
+        }
+        self.resolve(module, &segments)
+    }
+
+    /// The method `resolve_depth` returns the path of the definition named by `segments`,
+    /// looking for the definitions from the module, from the crate's root and from the imports.
+    fn resolve_depth(&self, module: &ModulePath, segments: &[String], depth: usize) -> Option<ModulePath> {
+        segments.split_first().and_then(|(first, rest)| {
+            let mut candidates: Vec<Vec<String>> = vec!(segments.to_vec());
+
+            candidates.extend(self.imports.iter()
+                                          .filter(|&&(ref from, _, _)| module.eq(from))
+                                          .filter_map(|&(_, ref alias, ref path)| match alias {
+                                              &Some(ref alias) if alias.eq(first) => {
+                                                  Some(path.iter().chain(rest.iter()).cloned().collect::<Vec<String>>())
+                                              },
+                                              &None => Some(path.iter().chain(segments.iter()).cloned().collect::<Vec<String>>()),
+                                              _ => None,
+                                          }));
+            candidates.iter()
+                      .filter_map(|candidate: &Vec<String>| candidate.split_last())
+                      .flat_map(|(name, prefix)|
+                          module.resolve(prefix)
+                                .into_iter()
+                                .map(move |parent: ModulePath| (parent, name)))
+                      .filter_map(|(parent, name): (ModulePath, &String)| {
+                          let path: ModulePath = parent.join(name);
+
+                          if self.definition(&path).is_some() {
+                              Some(path)
+                          } else if depth > 0 && parent.ne(module) {
+                              self.resolve_depth(&parent, &[name.clone()], depth - 1)
+                          } else {
+                              None
+                          }
+                      })
+                      .next()
+        })
+    }
+}
+
+impl <'a> From<&'a [(syn::Item, Rc<ModulePath>)]> for Scope<'a> {
+    fn from(list: &'a [(syn::Item, Rc<ModulePath>)]) -> Scope<'a> {
+        Scope {
+            definitions: list.iter()
+                             .flat_map(|&(ref item, ref path)|
+                                 item2ident(item).into_iter()
+                                                 .chain(foreign2idents(item))
+                                                 .map(move |ident| (path.join(ident.to_string()), item)))
+                             .collect::<Vec<(ModulePath, &'a syn::Item)>>(),
+            imports: list.iter()
+                         .filter_map(|&(ref item, ref path)|
+                             if let &syn::Item::Use(syn::ItemUse { ref leading_colon, ref tree, .. }) = item {
+                                 let prefix: Vec<String> = if leading_colon.is_some() {
+                                     vec!(String::from("crate"))
+                                 } else {
+                                     Vec::new()
+                                 };
+
+                                 Some(tree2imports(tree, prefix).into_iter()
+                                                                .map(|(alias, import)| (Rc::clone(path), alias, import))
+                                                                .collect::<Vec<(Rc<ModulePath>, Option<String>, Vec<String>)>>())
+                             } else {
+                                 None
+                             })
+                         .collect::<Vec<Vec<(Rc<ModulePath>, Option<String>, Vec<String>)>>>()
+                         .concat(),
+        }
+    }
+}
+
+/// The function `item2ident` returns the name of a type's definition.
+pub fn item2ident(item: &syn::Item) -> Option<&syn::Ident> {
+    match item {
+        &syn::Item::Trait(syn::ItemTrait { ref ident, .. }) => Some(ident),
+        &syn::Item::Struct(syn::ItemStruct { ref ident, .. }) => Some(ident),
+        &syn::Item::Enum(syn::ItemEnum { ref ident, .. }) => Some(ident),
+        &syn::Item::Union(syn::ItemUnion { ref ident, .. }) => Some(ident),
+        &syn::Item::Type(syn::ItemType { ref ident, .. }) => Some(ident),
+        _ => None,
+    }
+}
+
+/// The function `foreign2idents` returns the names of the extern types declared by an `extern` block.
+pub fn foreign2idents(item: &syn::Item) -> Vec<&syn::Ident> {
+    if let &syn::Item::ForeignMod(syn::ItemForeignMod { ref items, .. }) = item {
+        items.iter()
+             .filter_map(|item| if let &syn::ForeignItem::Type(syn::ForeignItemType { ref ident, .. }) = item {
+                 Some(ident)
+             } else {
+                 None
+             })
+             .collect::<Vec<&syn::Ident>>()
+    } else {
+        Vec::new()
+    }
+}
+
+/// The function `tree2imports` returns the names, or else globs, with the paths
+/// imported by a `use` declaration.
+fn tree2imports(tree: &syn::UseTree, mut prefix: Vec<String>) -> Vec<(Option<String>, Vec<String>)> {
+    match tree {
+        &syn::UseTree::Path(syn::UsePath { ref ident, ref tree, .. }) => {
+            prefix.push(ident.to_string());
+            tree2imports(tree, prefix)
+        },
+        &syn::UseTree::Name(syn::UseName { ref ident }) if ident.eq("self") => {
+            vec!((prefix.last().cloned(), prefix))
+        },
+        &syn::UseTree::Name(syn::UseName { ref ident }) => {
+            prefix.push(ident.to_string());
+            vec!((Some(ident.to_string()), prefix))
+        },
+        &syn::UseTree::Rename(syn::UseRename { ref ident, ref rename, .. }) => {
+            prefix.push(ident.to_string());
+            vec!((Some(rename.to_string()), prefix))
+        },
+        &syn::UseTree::Glob(_) => vec!((None, prefix)),
+        &syn::UseTree::Group(syn::UseGroup { ref items, .. }) => {
+            items.iter()
+                 .flat_map(|tree| tree2imports(tree, prefix.clone()))
+                 .collect::<Vec<(Option<String>, Vec<String>)>>()
+        },
+    }
+}
@@ -0,0 +1,43 @@
+use ::core::model::Model;
+use ::module::cfg::Cfg;
+
+/// The structure `Options` is the set of settings with which the modules are loaded and graphed:
+/// the configuration which drops the inactive items, the expansion of the macros,
+/// and the crate's own containers and smart pointers.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Options {
+    /// Enabled features and flags, which drop the inactive items when set.
+    pub cfg: Option<Cfg>,
+    /// Expands the invocations of the crate's `macro_rules!`.
+    pub expand: bool,
+    /// The crate's own containers and smart pointers, with the standard ones.
+    pub model: Model,
+}
+
+impl Options {
+
+    /// The constructor method `new` returns the options which keep every item
+    /// without expanding the macros, with the standard containers and smart pointers.
+    pub fn new() -> Options {
+        Options::default()
+    }
+
+    /// The method `cfg` drops the items disabled by the features and flags of `cfg`.
+    pub fn cfg(mut self, cfg: Cfg) -> Options {
+        self.cfg = Some(cfg);
+        self
+    }
+
+    /// The method `expand` expands the invocations of the crate's `macro_rules!`.
+    pub fn expand(mut self, expand: bool) -> Options {
+        self.expand = expand;
+        self
+    }
+
+    /// The method `model` reads the multiplicities and the ownerships of the crate's own
+    /// containers and smart pointers from `model`.
+    pub fn model(mut self, model: Model) -> Options {
+        self.model = model;
+        self
+    }
+}
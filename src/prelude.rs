@@ -3,6 +3,9 @@
 
 pub use ::DEFAULT_NAME_DOT;
 pub use ::DEFAULT_NAME_PNG;
+pub use ::DEFAULT_NAME_ROOT;
+pub use ::error::{Error, ParseError};
+pub use ::graphviz::Graphviz;
 pub use ::core::segment::Segment;
 pub use ::core::item::Item;
 pub use ::core::item::relation::Relation;
@@ -13,3 +16,7 @@ pub use ::core::item::state::abstraction::Abstract;
 pub use ::core::item::state::abstraction::extend::Trait;
 pub use ::core::item::state::abstraction::structure::Struct;
 pub use ::core::item::state::abstraction::enumerate::Enum;
+pub use ::core::item::state::ownership::Ownership;
+pub use ::core::model::Model;
+pub use ::module::cfg::Cfg;
+pub use ::options::Options;
@@ -20,8 +20,8 @@ fn test_aggregation() {
     ndAmut[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nAmut|- b: *mut B}"][shape="record"];
     ndAconst[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nAconst|- b: *const B}"][shape="record"];
     ndB[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nB}"][shape="record"];
-    ndB -> ndAmut[label=""][arrowhead="odiamond"];
-    ndB -> ndAconst[label=""][arrowhead="odiamond"];
+    ndB -> ndAmut[label=""][arrowhead="odiamond"][taillabel=<b 1>];
+    ndB -> ndAconst[label=""][arrowhead="odiamond"][taillabel=<b 1>];
 }
 "#);
 }
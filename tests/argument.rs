@@ -0,0 +1,83 @@
+#![allow(dead_code, unused_variables)]
+extern crate mml;
+
+struct Config {
+}
+
+struct MyConfig {
+}
+
+trait Visitor {
+}
+
+trait Apply {
+    fn apply(&self, config: &MyConfig);
+}
+
+struct Parser {
+}
+
+impl Parser {
+    fn configure(&self, configs: Vec<Config>) {
+    }
+
+    fn walk<V: Visitor>(&self, visitor: V) {
+    }
+}
+
+impl Apply for Parser {
+    fn apply(&self, config: &MyConfig) {
+    }
+}
+
+pub fn parse<V: Visitor>(parser: &Parser, configs: &[Config], visitor: V) -> Option<MyConfig> {
+    None
+}
+
+#[test]
+#[cfg(not(feature = "fn-emilgardis"))]
+fn test_argument() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/argument.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndConfig[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nConfig}"][shape="record"];
+    ndMyConfig[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nMyConfig}"][shape="record"];
+    ndVisitor[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nVisitor|}"][shape="record"];
+    ndApply[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nApply|apply(&amp;Self, &amp;MyConfig) \{abstract\}}"][shape="record"];
+    ndParser[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nParser|- configure(&amp;self, configs: Vec&lt;Config&gt;)\n- walk(&amp;self, visitor: V)}"][shape="record"];
+    utargument[label="{&lt;&lt;&lt;Utility&gt;&gt;&gt;\nargument|+&#818; &#818;p&#818;a&#818;r&#818;s&#818;e&#818;(&#818;p&#818;a&#818;r&#818;s&#818;e&#818;r&#818;:&#818; &#818;&amp;&#818;P&#818;a&#818;r&#818;s&#818;e&#818;r&#818;,&#818; &#818;c&#818;o&#818;n&#818;f&#818;i&#818;g&#818;s&#818;:&#818; &#818;&amp;&#818;[&#818;C&#818;o&#818;n&#818;f&#818;i&#818;g&#818;]&#818;,&#818; &#818;v&#818;i&#818;s&#818;i&#818;t&#818;o&#818;r&#818;:&#818; &#818;V&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;O&#818;p&#818;t&#818;i&#818;o&#818;n&#818;&lt;&#818;M&#818;y&#818;C&#818;o&#818;n&#818;f&#818;i&#818;g&#818;&gt;&#818;}"][shape="record"];
+    ndConfig -> ndParser[label=""][style="dashed"][arrowhead="vee"];
+    ndConfig -> utargument[label=""][style="dashed"][arrowhead="vee"];
+    ndMyConfig -> ndParser[label=""][style="dashed"][arrowhead="vee"];
+    ndMyConfig -> utargument[label=""][arrowhead="vee"][taillabel=<parse() 0..1>];
+    ndVisitor -> ndParser[label=""][style="dashed"][arrowhead="vee"];
+    ndVisitor -> utargument[label=""][style="dashed"][arrowhead="vee"];
+    ndApply -> ndParser[label=""][style="dashed"][arrowhead="onormal"];
+    ndParser -> utargument[label=""][style="dashed"][arrowhead="vee"];
+}
+"#);
+}
+
+#[test]
+#[cfg(feature = "fn-emilgardis")]
+fn test_argument() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/argument.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndConfig[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nConfig}"][shape="record"];
+    ndMyConfig[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nMyConfig}"][shape="record"];
+    ndVisitor[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nVisitor|}"][shape="record"];
+    ndApply[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nApply|apply(&amp;Self, &amp;MyConfig) \{abstract\}}"][shape="record"];
+    ndParser[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nParser|- fn configure(&amp;self, configs: Vec&lt;Config&gt;)\n- fn walk(&amp;self, visitor: V)}"][shape="record"];
+    utargument[label="{&lt;&lt;&lt;Utility&gt;&gt;&gt;\nargument|+&#818; &#818;f&#818;n&#818; &#818;p&#818;a&#818;r&#818;s&#818;e&#818;(&#818;p&#818;a&#818;r&#818;s&#818;e&#818;r&#818;:&#818; &#818;&amp;&#818;P&#818;a&#818;r&#818;s&#818;e&#818;r&#818;,&#818; &#818;c&#818;o&#818;n&#818;f&#818;i&#818;g&#818;s&#818;:&#818; &#818;&amp;&#818;[&#818;C&#818;o&#818;n&#818;f&#818;i&#818;g&#818;]&#818;,&#818; &#818;v&#818;i&#818;s&#818;i&#818;t&#818;o&#818;r&#818;:&#818; &#818;V&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;O&#818;p&#818;t&#818;i&#818;o&#818;n&#818;&lt;&#818;M&#818;y&#818;C&#818;o&#818;n&#818;f&#818;i&#818;g&#818;&gt;&#818;}"][shape="record"];
+    ndConfig -> ndParser[label=""][style="dashed"][arrowhead="vee"];
+    ndConfig -> utargument[label=""][style="dashed"][arrowhead="vee"];
+    ndMyConfig -> ndParser[label=""][style="dashed"][arrowhead="vee"];
+    ndMyConfig -> utargument[label=""][arrowhead="vee"][taillabel=<parse() 0..1>];
+    ndVisitor -> ndParser[label=""][style="dashed"][arrowhead="vee"];
+    ndVisitor -> utargument[label=""][style="dashed"][arrowhead="vee"];
+    ndApply -> ndParser[label=""][style="dashed"][arrowhead="onormal"];
+    ndParser -> utargument[label=""][style="dashed"][arrowhead="vee"];
+}
+"#);
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code, clippy::unused_unit)]
+extern crate mml;
+
+use std::fmt::Display;
+
+trait Counter {
+    type Output: Display + Clone;
+
+    const START: usize = 0;
+    const LIMIT: usize;
+
+    fn reset(&mut self);
+
+    fn clear(&mut self) -> ();
+
+    fn next(&mut self) -> Self::Output;
+}
+
+#[test]
+fn test_associated() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/associated.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndCounter[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nCounter|type Output: Display + Clone|const START: usize = 0\nconst LIMIT: usize|reset(&amp;mut Self) \{abstract\}\nclear(&amp;mut Self) \{abstract\}\nnext(&amp;mut Self) -&gt; Self::Output \{abstract\}}"][shape="record"];
+}
+"#);
+}
@@ -36,11 +36,11 @@ fn test_association() {
     assert_eq!(
         String::from_utf8(mml::rs2dot("tests/association.rs").unwrap()).unwrap(),
         r#"digraph ml {
-    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- b() -&gt; B}"][shape="record"];
-    ndAb[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nAb|- b() -&gt; B}"][shape="record"];
-    ndB[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nB|- a() -&gt; Ab}"][shape="record"];
-    ndAb -> ndB[label=""][arrowhead="none"];
-    ndB -> ndA[label=""][arrowhead="vee"];
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|-&#818; &#818;b&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;B&#818;}"][shape="record"];
+    ndAb[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nAb|-&#818; &#818;b&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;B&#818;}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nB|-&#818; &#818;a&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;A&#818;b&#818;}"][shape="record"];
+    ndAb -> ndB[label=""][arrowhead="none"][taillabel=<a() 1>][headlabel=<b() 1>];
+    ndB -> ndA[label=""][arrowhead="vee"][taillabel=<b() 1>];
 }
 "#);
 }
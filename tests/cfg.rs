@@ -0,0 +1,29 @@
+extern crate mml;
+
+use mml::prelude::Cfg;
+
+#[test]
+fn test_cfg() {
+    assert_eq!(
+        String::from_utf8(mml::src2dot_with("tests/cfg", &Cfg::new().feature("implem")).unwrap()).unwrap(),
+        r#"digraph ml {
+    ndImplem[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nImplem}"][shape="record"];
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- implem: Implem}"][shape="record"];
+    ndImplem -> ndA[label=""][arrowhead="diamond"][taillabel=<implem 1>];
+}
+"#);
+}
+
+#[test]
+fn test_cfg_annotate() {
+    assert_eq!(
+        String::from_utf8(mml::src2dot_with("tests/cfg", &Cfg::new().feature("implem").flag("unix").annotate(true)).unwrap()).unwrap(),
+        r#"digraph ml {
+    ndImplem[label="{&lt;&lt;&lt;cfg(feature = &quot;implem&quot;)&gt;&gt;&gt;\n&lt;&lt;&lt;Structure&gt;&gt;&gt;\nImplem}"][shape="record"];
+    ndPlatform[label="{&lt;&lt;&lt;cfg(any(unix, target_os = &quot;redox&quot;))&gt;&gt;&gt;\n&lt;&lt;&lt;Structure&gt;&gt;&gt;\nPlatform}"][shape="record"];
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- implem: Implem}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;cfg(unix)&gt;&gt;&gt;\n&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nB|Real}"][shape="record"];
+    ndImplem -> ndA[label=""][arrowhead="diamond"][taillabel=<implem 1>];
+}
+"#);
+}
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "implem")]
+pub struct Implem;
+
+#[cfg(not(feature = "implem"))]
+pub struct Fallback;
+
+#[cfg(any(unix, target_os = "redox"))]
+pub struct Platform;
+
+pub struct A {
+    #[cfg(feature = "implem")]
+    implem: Implem,
+    #[cfg(not(feature = "implem"))]
+    fallback: Fallback,
+}
+
+#[cfg_attr(feature = "implem", cfg(unix))]
+pub enum B {
+    #[cfg(test)]
+    Mock,
+    Real,
+}
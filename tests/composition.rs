@@ -16,7 +16,7 @@ fn test_composition() {
         r#"digraph ml {
     ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- b: B}"][shape="record"];
     ndB[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nB}"][shape="record"];
-    ndB -> ndA[label=""][arrowhead="diamond"];
+    ndB -> ndA[label=""][arrowhead="diamond"][taillabel=<b 1>];
 }
 "#);
 }
@@ -17,7 +17,7 @@ fn test_dependency() {
     assert_eq!(
         String::from_utf8(mml::rs2dot("tests/dependency.rs").unwrap()).unwrap(),
         r#"digraph ml {
-    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- b(b: &amp;B)}"][shape="record"];
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|-&#818; &#818;b&#818;(&#818;b&#818;:&#818; &#818;&amp;&#818;B&#818;)&#818;}"][shape="record"];
     ndB[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nB}"][shape="record"];
     ndB -> ndA[label=""][style="dashed"][arrowhead="vee"];
 }
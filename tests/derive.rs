@@ -0,0 +1,37 @@
+extern crate mml;
+
+#[test]
+#[cfg(not(feature = "derive-realization"))]
+fn test_derive() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/derive/lib.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndDescribe[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nDescribe|}"][shape="record"];
+    ndA[label="{&lt;&lt;&lt;derive(Debug, Clone, PartialEq)&gt;&gt;&gt;\n&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;derive(Debug, Describe)&gt;&gt;&gt;\n&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nB|C(A)}"][shape="record"];
+    ndA -> ndB[label=""][arrowhead="diamond"][taillabel=<C 1>];
+}
+"#);
+}
+
+#[test]
+#[cfg(feature = "derive-realization")]
+fn test_derive() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/derive/lib.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndDescribe[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nDescribe|}"][shape="record"];
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nB|C(A)}"][shape="record"];
+    ndDebug[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nDebug}"][shape="record"];
+    ndClone[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nClone}"][shape="record"];
+    ndPartialEq[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nPartialEq}"][shape="record"];
+    ndDescribe -> ndB[label=""][style="dashed"][arrowhead="onormal"];
+    ndA -> ndB[label=""][arrowhead="diamond"][taillabel=<C 1>];
+    ndDebug -> ndA[label=""][style="dashed"][arrowhead="onormal"];
+    ndDebug -> ndB[label=""][style="dashed"][arrowhead="onormal"];
+    ndClone -> ndA[label=""][style="dashed"][arrowhead="onormal"];
+    ndPartialEq -> ndA[label=""][style="dashed"][arrowhead="onormal"];
+}
+"#);
+}
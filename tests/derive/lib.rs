@@ -0,0 +1,11 @@
+pub trait Describe {
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct A {
+}
+
+#[derive(Debug, Describe)]
+pub enum B {
+    C(A),
+}
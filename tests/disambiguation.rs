@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+extern crate mml;
+
+use b::Error as Failure;
+
+mod a {
+    pub struct Error {
+    }
+}
+
+mod b {
+    pub struct Error {
+    }
+}
+
+struct Config {
+    a: a::Error,
+    b: *mut Failure,
+}
+
+#[test]
+fn test_disambiguation() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/disambiguation.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndConfig[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nConfig|- a: a::Error\n- b: *mut Failure}"][shape="record"];
+    nddisambiguation_a_Error[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nError}"][shape="record"];
+    nddisambiguation_b_Error[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nError}"][shape="record"];
+    nddisambiguation_a_Error -> ndConfig[label=""][arrowhead="diamond"][taillabel=<a 1>];
+    nddisambiguation_b_Error -> ndConfig[label=""][arrowhead="odiamond"][taillabel=<b 1>];
+}
+"#);
+}
@@ -0,0 +1,58 @@
+extern crate mml;
+
+/// A point of the plane.
+///
+/// The details aren't shown.
+pub struct A {
+    /// The "horizontal"
+    /// coordinate.
+    x: i32,
+    y: i32,
+}
+
+impl A {
+    /// Returns the origin.
+    pub fn origin() -> A {
+        A { x: 0, y: 0 }
+    }
+}
+
+pub enum B {
+    /// Nothing.
+    C,
+    D(A),
+}
+
+#[test]
+#[cfg(not(feature = "notes"))]
+fn test_doc() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/doc.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- x: i32\n- y: i32|+&#818; &#818;o&#818;r&#818;i&#818;g&#818;i&#818;n&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;A&#818;}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nB|C\nD(A)}"][shape="record"];
+    ndA -> ndB[label=""][arrowhead="diamond"][taillabel=<D 1>];
+    ndA[tooltip="A point of the plane.\nx: The \"horizontal\" coordinate.\norigin: Returns the origin."];
+    ndB[tooltip="C: Nothing."];
+}
+"#);
+}
+
+#[test]
+#[cfg(feature = "notes")]
+fn test_doc() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/doc.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- x: i32\n- y: i32|+&#818; &#818;o&#818;r&#818;i&#818;g&#818;i&#818;n&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;A&#818;}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nB|C\nD(A)}"][shape="record"];
+    ndA -> ndB[label=""][arrowhead="diamond"][taillabel=<D 1>];
+    ndA[tooltip="A point of the plane.\nx: The \"horizontal\" coordinate.\norigin: Returns the origin."];
+    ndA_note[label="A point of the plane.\nx: The \"horizontal\" coordinate.\norigin: Returns the origin."][shape="note"];
+    ndA_note -> ndA[style="dashed"][arrowhead="none"];
+    ndB[tooltip="C: Nothing."];
+    ndB_note[label="C: Nothing."][shape="note"];
+    ndB_note -> ndB[style="dashed"][arrowhead="none"];
+}
+"#);
+}
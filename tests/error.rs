@@ -0,0 +1,57 @@
+extern crate mml;
+
+use std::ops::BitAnd;
+use std::path::Path;
+
+#[test]
+fn test_error() {
+    match mml::src2dot("tests/error") {
+        Err(mml::error::Error::Parse(diagnostics)) => {
+            assert!(!diagnostics.is_empty());
+            assert!(diagnostics.iter().all(|diagnostic| diagnostic.path.eq(Path::new("tests/error/bad.rs"))));
+        },
+        _ => panic!("the file `tests/error/bad.rs` should be reported"),
+    }
+}
+
+#[test]
+fn test_error_lenient() {
+    let (dot, diagnostics) = mml::src2dot_lenient("tests/error").unwrap();
+
+    assert_eq!(
+        String::from_utf8(dot).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA}"][shape="record"];
+}
+"#);
+    assert!(!diagnostics.is_empty());
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.path.eq(Path::new("tests/error/bad.rs"))
+                                                    .bitand(diagnostic.span.is_some())));
+}
+
+#[test]
+fn test_error_missing() {
+    match mml::src2dot("tests/missing") {
+        Err(mml::error::Error::Parse(diagnostics)) => {
+            assert_eq!(diagnostics.len(), 1);
+            assert!(diagnostics.iter().all(|diagnostic| diagnostic.path.eq(Path::new("tests/missing/lib.rs"))
+                                                            .bitand(diagnostic.message.contains("gone"))));
+        },
+        _ => panic!("the missing file of `tests/missing/lib.rs` should be reported"),
+    }
+}
+
+#[test]
+fn test_error_missing_lenient() {
+    let (dot, diagnostics) = mml::src2dot_lenient("tests/missing").unwrap();
+
+    assert_eq!(
+        String::from_utf8(dot).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA}"][shape="record"];
+}
+"#);
+    assert_eq!(diagnostics.len(), 2);
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.path.eq(Path::new("tests/missing/lib.rs"))
+                                                    .bitand(diagnostic.span.is_some())));
+}
@@ -0,0 +1,3 @@
+struct B {
+    a: ,
+}
@@ -0,0 +1,50 @@
+extern crate mml;
+
+use mml::prelude::{Cfg, Options};
+
+#[test]
+fn test_expand() {
+    assert_eq!(
+        String::from_utf8(mml::src2dot_with_options("tests/expand", &Options::new().expand(true)).unwrap()).unwrap(),
+        r#"digraph ml {
+    ndMeter[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nMeter}"][shape="record"];
+    ndYard[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nYard}"][shape="record"];
+    ndLength[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nLength|+ 0: Meter|+ get(&amp;self) -&gt; Meter}"][shape="record"];
+    ndError[label="{&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nError|Io(Length)\nParse(Meter)}"][shape="record"];
+    ndRuler[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nRuler|- length: Length|+ length(&amp;self) -&gt; &amp;Length}"][shape="record"];
+    ndMeter -> ndLength[label=""][arrowhead="diamond"][taillabel=<.0 1<br/>get() 1>];
+    ndMeter -> ndError[label=""][arrowhead="diamond"][taillabel=<Parse 1>];
+    ndLength -> ndError[label=""][arrowhead="diamond"][taillabel=<Io 1>];
+    ndLength -> ndRuler[label=""][arrowhead="diamond"][taillabel=<length 1<br/>length() 1>];
+}
+"#);
+}
+
+#[test]
+fn test_expand_cfg() {
+    assert_eq!(
+        String::from_utf8(mml::src2dot_with_options("tests/expand", &Options::new().cfg(Cfg::new()).expand(true)).unwrap()).unwrap(),
+        r#"digraph ml {
+    ndMeter[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nMeter}"][shape="record"];
+    ndLength[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nLength|+ 0: Meter|+ get(&amp;self) -&gt; Meter}"][shape="record"];
+    ndError[label="{&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nError|Io(Length)\nParse(Meter)}"][shape="record"];
+    ndRuler[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nRuler|- length: Length|+ length(&amp;self) -&gt; &amp;Length}"][shape="record"];
+    ndMeter -> ndLength[label=""][arrowhead="diamond"][taillabel=<.0 1<br/>get() 1>];
+    ndMeter -> ndError[label=""][arrowhead="diamond"][taillabel=<Parse 1>];
+    ndLength -> ndError[label=""][arrowhead="diamond"][taillabel=<Io 1>];
+    ndLength -> ndRuler[label=""][arrowhead="diamond"][taillabel=<length 1<br/>length() 1>];
+}
+"#);
+}
+
+#[test]
+fn test_unexpanded() {
+    assert_eq!(
+        String::from_utf8(mml::src2dot("tests/expand").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndMeter[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nMeter}"][shape="record"];
+    ndYard[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nYard}"][shape="record"];
+    ndRuler[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nRuler|- length: Length|}"][shape="record"];
+}
+"#);
+}
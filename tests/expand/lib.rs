@@ -0,0 +1,48 @@
+macro_rules! newtype {
+    ($(#[$meta:meta])* $vis:vis $name:ident($ty:ty)) => {
+        $(#[$meta])*
+        $vis struct $name(pub $ty);
+
+        impl $name {
+            pub fn get(&self) -> $ty {
+                self.0
+            }
+        }
+    };
+}
+
+macro_rules! errors {
+    ($name:ident { $($variant:ident($ty:ty)),* $(,)? }) => {
+        pub enum $name {
+            $($variant($ty)),*
+        }
+    };
+}
+
+macro_rules! accessor {
+    ($field:ident: $ty:ty) => {
+        pub fn $field(&self) -> &$ty {
+            &self.$field
+        }
+    };
+}
+
+pub struct Meter;
+
+#[cfg(feature = "imperial")]
+pub struct Yard;
+
+newtype!(pub Length(Meter));
+
+errors!(Error {
+    Io(Length),
+    Parse(Meter),
+});
+
+pub struct Ruler {
+    length: Length,
+}
+
+impl Ruler {
+    accessor!(length: Length);
+}
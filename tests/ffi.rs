@@ -0,0 +1,18 @@
+extern crate mml;
+
+#[test]
+fn test_ffi() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/ffi/lib.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndRawHandle[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nRawHandle}"][shape="record"];
+    ndHandle[label="{&lt;&lt;&lt;Alias&gt;&gt;&gt;\nHandle|*mut RawHandle}"][shape="record"];
+    ndValue[label="{&lt;&lt;&lt;Union&gt;&gt;&gt;\nValue|- handle: Handle\n+ bits: u64}"][shape="record"];
+    ndOpaque[label="{&lt;&lt;&lt;Opaque&gt;&gt;&gt;\nOpaque}"][shape="record"];
+    ndContext[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nContext|- opaque: *const Opaque}"][shape="record"];
+    ndRawHandle -> ndHandle[label=""][arrowhead="odiamond"][taillabel=<1>];
+    ndHandle -> ndValue[label=""][arrowhead="diamond"][taillabel=<handle 1>];
+    ndOpaque -> ndContext[label=""][arrowhead="odiamond"][taillabel=<opaque 1>];
+}
+"#);
+}
@@ -0,0 +1,19 @@
+#![feature(extern_types)]
+
+struct RawHandle {
+}
+
+type Handle = *mut RawHandle;
+
+union Value {
+    handle: Handle,
+    pub bits: u64,
+}
+
+extern "C" {
+    type Opaque;
+}
+
+struct Context {
+    opaque: *const Opaque,
+}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+extern crate mml;
+
+trait Named {
+    fn name(&self) -> String;
+}
+
+trait Shape: Named + Clone {
+    fn area(&self) -> f64;
+}
+
+#[test]
+fn test_generalization() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/generalization.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndNamed[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nNamed|name(&amp;Self) -&gt; String \{abstract\}}"][shape="record"];
+    ndShape[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nShape|area(&amp;Self) -&gt; f64 \{abstract\}}"][shape="record"];
+    ndNamed -> ndShape[label=""][arrowhead="onormal"];
+}
+"#);
+}
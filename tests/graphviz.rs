@@ -0,0 +1,56 @@
+extern crate mml;
+
+use mml::error::Error;
+use mml::graphviz::Graphviz;
+
+#[test]
+fn test_graphviz_not_found() {
+    match Graphviz::new("mml-graphviz-not-found").content2svg(b"digraph ml {\n}\n".to_vec()) {
+        Err(Error::DotNotFound(program)) => assert_eq!(program.to_str(), Some("mml-graphviz-not-found")),
+        _ => panic!("the program `mml-graphviz-not-found` shouldn't be found"),
+    }
+}
+
+#[test]
+fn test_graphviz_failed() {
+    match Graphviz::default().arg("-Tnope").content2svg(b"digraph ml {\n}\n".to_vec()) {
+        Err(Error::DotFailed(status, stderr)) => {
+            assert!(!status.success());
+            assert!(stderr.contains("nope"));
+        },
+        // Without *Graphviz* installed, the failure is covered by `test_graphviz_failed_status`.
+        Err(Error::DotNotFound(_)) => {},
+        _ => panic!("the format `nope` shouldn't be known from Graphviz"),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_graphviz_failed_status() {
+    match Graphviz::new("sh").content2svg(b"digraph ml {\n}\n".to_vec()) {
+        Err(Error::DotFailed(status, stderr)) => {
+            assert!(!status.success());
+            assert!(stderr.contains("-T"));
+        },
+        _ => panic!("the program `sh` should refuse the option `-Tsvg`"),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_graphviz_noisy() {
+    use std::env;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+
+    let program: PathBuf = env::temp_dir().join(format!("mml-graphviz-noisy-{}", std::process::id()));
+
+    fs::write(&program, "#!/bin/sh\nhead -c 1048576 /dev/zero >&2\ncat >/dev/null\necho '<svg/>'\n").unwrap();
+    fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let svg = Graphviz::new(&program).content2svg(vec!(b'\n'; 1048576));
+
+    fs::remove_file(&program).unwrap();
+    assert_eq!(svg.unwrap(), b"<svg/>\n".to_vec());
+}
@@ -0,0 +1,73 @@
+#![allow(dead_code, non_camel_case_types)]
+extern crate mml;
+
+struct Café {
+    x: i32,
+}
+
+mod r#type {
+    pub struct Crème {
+    }
+}
+
+mod brûlée {
+    pub struct Crème {
+    }
+}
+
+mod r#match {
+    pub fn f() {
+    }
+}
+
+mod bar {
+    pub struct foo {
+    }
+}
+
+mod foo {
+    pub fn g() {
+    }
+}
+
+struct Dessert {
+    a: r#type::Crème,
+    b: brûlée::Crème,
+}
+
+#[test]
+#[cfg(not(feature = "fn-emilgardis"))]
+fn test_identifier() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/identifier.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndCaf_u00e9[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nCaf\u{e9}|- x: i32}"][shape="record"];
+    ndDessert[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nDessert|- a: r#type::Cr\u{e8}me\n- b: br\u{fb}l\u{e9}e::Cr\u{e8}me}"][shape="record"];
+    ndidentifier_type_Cr_u00e8me[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nCr\u{e8}me}"][shape="record"];
+    ndidentifier_br_u00fbl_u00e9e_Cr_u00e8me[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nCr\u{e8}me}"][shape="record"];
+    utmatch[label="{&lt;&lt;&lt;Utility&gt;&gt;&gt;\nmatch|+&#818; &#818;f&#818;(&#818;)&#818;}"][shape="record"];
+    ndfoo[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nfoo}"][shape="record"];
+    utfoo[label="{&lt;&lt;&lt;Utility&gt;&gt;&gt;\nfoo|+&#818; &#818;g&#818;(&#818;)&#818;}"][shape="record"];
+    ndidentifier_type_Cr_u00e8me -> ndDessert[label=""][arrowhead="diamond"][taillabel=<a 1>];
+    ndidentifier_br_u00fbl_u00e9e_Cr_u00e8me -> ndDessert[label=""][arrowhead="diamond"][taillabel=<b 1>];
+}
+"#);
+}
+#[test]
+#[cfg(feature = "fn-emilgardis")]
+fn test_identifier() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/identifier.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndCaf_u00e9[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nCaf\u{e9}|- x: i32}"][shape="record"];
+    ndDessert[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nDessert|- a: r#type::Cr\u{e8}me\n- b: br\u{fb}l\u{e9}e::Cr\u{e8}me}"][shape="record"];
+    ndidentifier_type_Cr_u00e8me[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nCr\u{e8}me}"][shape="record"];
+    ndidentifier_br_u00fbl_u00e9e_Cr_u00e8me[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nCr\u{e8}me}"][shape="record"];
+    utmatch[label="{&lt;&lt;&lt;Utility&gt;&gt;&gt;\nmatch|+&#818; &#818;f&#818;n&#818; &#818;f&#818;(&#818;)&#818;}"][shape="record"];
+    ndfoo[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nfoo}"][shape="record"];
+    utfoo[label="{&lt;&lt;&lt;Utility&gt;&gt;&gt;\nfoo|+&#818; &#818;f&#818;n&#818; &#818;g&#818;(&#818;)&#818;}"][shape="record"];
+    ndidentifier_type_Cr_u00e8me -> ndDessert[label=""][arrowhead="diamond"][taillabel=<a 1>];
+    ndidentifier_br_u00fbl_u00e9e_Cr_u00e8me -> ndDessert[label=""][arrowhead="diamond"][taillabel=<b 1>];
+}
+"#);
+}
@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+extern crate mml;
+
+struct A {
+}
+
+struct B {
+}
+
+impl A {
+    fn b(&self) -> B {
+        B {
+        }
+    }
+}
+
+mod c {
+    impl super::B {
+        pub fn a(&self) -> super::A {
+            super::A {
+            }
+        }
+    }
+}
+
+#[test]
+fn test_implementation() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/implementation.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- b(&amp;self) -&gt; B}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nB|+ a(&amp;self) -&gt; super::A}"][shape="record"];
+    ndA -> ndB[label=""][arrowhead="none"][taillabel=<a() 1>][headlabel=<b() 1>];
+}
+"#);
+}
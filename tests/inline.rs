@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+extern crate mml;
+
+use b::B;
+
+struct A {
+    b: B,
+}
+
+mod b {
+    pub struct B {
+    }
+}
+
+#[test]
+fn test_inline() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/inline.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- b: B}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nB}"][shape="record"];
+    ndB -> ndA[label=""][arrowhead="diamond"][taillabel=<b 1>];
+}
+"#);
+}
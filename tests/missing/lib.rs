@@ -0,0 +1,8 @@
+#[path = "gone.rs"]
+mod gone;
+
+#[path = concat!(env!("OUT_DIR"), "/generated.rs")]
+mod generated;
+
+pub struct A {
+}
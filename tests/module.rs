@@ -0,0 +1,13 @@
+extern crate mml;
+
+#[test]
+fn test_module() {
+    assert_eq!(
+        String::from_utf8(mml::src2dot("tests/module").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndB[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nB|- a: A}"][shape="record"];
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA}"][shape="record"];
+    ndA -> ndB[label=""][arrowhead="diamond"][taillabel=<a 1>];
+}
+"#);
+}
@@ -0,0 +1,7 @@
+mod a;
+
+use a::A;
+
+struct B {
+    a: A,
+}
@@ -0,0 +1,57 @@
+extern crate mml;
+
+use mml::prelude::{Model, Options};
+
+#[test]
+fn test_multiplicity() {
+    assert_eq!(
+        String::from_utf8(mml::src2dot("tests/multiplicity").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndNode[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nNode}"][shape="record"];
+    ndEdge[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nEdge}"][shape="record"];
+    ndLabel[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nLabel}"][shape="record"];
+    ndBag[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nBag&lt;T&gt;|- items: Vec&lt;T&gt;}"][shape="record"];
+    ndGraph[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nGraph|- root: Node\n- parent: Option&lt;Box&lt;Node&gt;&gt;\n- corners: [Node; 4]\n- grid: [[Node; 4294967296]; 8589934592]\n- cells: [[Node; 4]; 8]\n- edges: Vec&lt;Option&lt;Edge&gt;&gt;\n- labels: HashMap&lt;String, Label&gt;\n- bag: Bag&lt;Label&gt;}"][shape="record"];
+    ndNode -> ndGraph[label=""][arrowhead="diamond"][taillabel=<root 1<br/>parent 0..1<br/>corners 4<br/>grid 8589934592*4294967296<br/>cells 32>];
+    ndEdge -> ndGraph[label=""][arrowhead="diamond"][taillabel=<edges 0..*>];
+    ndLabel -> ndGraph[label=""][arrowhead="diamond"][taillabel=<labels 0..*<br/>bag 1>];
+    ndBag -> ndGraph[label=""][arrowhead="diamond"][taillabel=<bag 1>];
+}
+"#);
+}
+
+#[test]
+fn test_multiplicity_container() {
+    assert_eq!(
+        String::from_utf8(mml::src2dot_with_options("tests/multiplicity", &Options::new().model(Model::new().container("Bag", "0..*"))).unwrap()).unwrap(),
+        r#"digraph ml {
+    ndNode[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nNode}"][shape="record"];
+    ndEdge[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nEdge}"][shape="record"];
+    ndLabel[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nLabel}"][shape="record"];
+    ndBag[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nBag&lt;T&gt;|- items: Vec&lt;T&gt;}"][shape="record"];
+    ndGraph[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nGraph|- root: Node\n- parent: Option&lt;Box&lt;Node&gt;&gt;\n- corners: [Node; 4]\n- grid: [[Node; 4294967296]; 8589934592]\n- cells: [[Node; 4]; 8]\n- edges: Vec&lt;Option&lt;Edge&gt;&gt;\n- labels: HashMap&lt;String, Label&gt;\n- bag: Bag&lt;Label&gt;}"][shape="record"];
+    ndNode -> ndGraph[label=""][arrowhead="diamond"][taillabel=<root 1<br/>parent 0..1<br/>corners 4<br/>grid 8589934592*4294967296<br/>cells 32>];
+    ndEdge -> ndGraph[label=""][arrowhead="diamond"][taillabel=<edges 0..*>];
+    ndLabel -> ndGraph[label=""][arrowhead="diamond"][taillabel=<labels 0..*<br/>bag 0..*>];
+    ndBag -> ndGraph[label=""][arrowhead="diamond"][taillabel=<bag 1>];
+}
+"#);
+}
+
+#[test]
+fn test_multiplicity_container_file() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot_with_options("tests/multiplicity/lib.rs", &Options::new().model(Model::new().container("Bag", "0..*"))).unwrap()).unwrap(),
+        r#"digraph ml {
+    ndNode[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nNode}"][shape="record"];
+    ndEdge[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nEdge}"][shape="record"];
+    ndLabel[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nLabel}"][shape="record"];
+    ndBag[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nBag&lt;T&gt;|- items: Vec&lt;T&gt;}"][shape="record"];
+    ndGraph[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nGraph|- root: Node\n- parent: Option&lt;Box&lt;Node&gt;&gt;\n- corners: [Node; 4]\n- grid: [[Node; 4294967296]; 8589934592]\n- cells: [[Node; 4]; 8]\n- edges: Vec&lt;Option&lt;Edge&gt;&gt;\n- labels: HashMap&lt;String, Label&gt;\n- bag: Bag&lt;Label&gt;}"][shape="record"];
+    ndNode -> ndGraph[label=""][arrowhead="diamond"][taillabel=<root 1<br/>parent 0..1<br/>corners 4<br/>grid 8589934592*4294967296<br/>cells 32>];
+    ndEdge -> ndGraph[label=""][arrowhead="diamond"][taillabel=<edges 0..*>];
+    ndLabel -> ndGraph[label=""][arrowhead="diamond"][taillabel=<labels 0..*<br/>bag 0..*>];
+    ndBag -> ndGraph[label=""][arrowhead="diamond"][taillabel=<bag 1>];
+}
+"#);
+}
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+pub struct Node {
+}
+
+pub struct Edge {
+}
+
+pub struct Label {
+}
+
+pub struct Bag<T> {
+    items: Vec<T>,
+}
+
+pub struct Graph {
+    root: Node,
+    parent: Option<Box<Node>>,
+    corners: [Node; 4],
+    grid: [[Node; 4294967296]; 8589934592],
+    cells: [[Node; 4]; 8],
+    edges: Vec<Option<Edge>>,
+    labels: HashMap<String, Label>,
+    bag: Bag<Label>,
+}
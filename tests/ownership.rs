@@ -0,0 +1,47 @@
+extern crate mml;
+
+use mml::prelude::{Model, Options, Ownership};
+
+#[test]
+fn test_ownership() {
+    assert_eq!(
+        String::from_utf8(mml::src2dot("tests/ownership").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndGc[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nGc&lt;T&gt;|- ptr: *const T}"][shape="record"];
+    ndLeaf[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nLeaf}"][shape="record"];
+    ndNode[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nNode}"][shape="record"];
+    ndConfig[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nConfig}"][shape="record"];
+    ndParent[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nParent}"][shape="record"];
+    ndHandle[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nHandle}"][shape="record"];
+    ndTree[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nTree&lt;\'a&gt;|- inline: Leaf\n- boxed: Box&lt;Leaf&gt;\n- borrowed: &amp;\'a Node\n- children: Vec&lt;Rc&lt;Node&gt;&gt;\n- config: Arc&lt;Config&gt;\n- parent: Weak&lt;Parent&gt;\n- handle: Gc&lt;Handle&gt;}"][shape="record"];
+    ndGc -> ndTree[label=""][arrowhead="diamond"][taillabel=<handle 1>];
+    ndLeaf -> ndTree[label=""][arrowhead="diamond"][taillabel=<inline 1<br/>boxed 1>];
+    ndNode -> ndTree[label=""][arrowhead="odiamond"][taillabel=<borrowed 1<br/>children 0..*>];
+    ndConfig -> ndTree[label=""][arrowhead="odiamond"][taillabel=<config 1>];
+    ndParent -> ndTree[label=""][arrowhead="vee"][taillabel=<parent 0..1>];
+    ndHandle -> ndTree[label=""][arrowhead="diamond"][taillabel=<handle 1>];
+}
+"#);
+}
+
+#[test]
+fn test_ownership_pointer() {
+    assert_eq!(
+        String::from_utf8(mml::crate2dot_with_options("tests/ownership/lib.rs", &Options::new().model(Model::new().pointer("Gc", Ownership::Shared))).unwrap()).unwrap(),
+        r#"digraph ml {
+    ndGc[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nGc&lt;T&gt;|- ptr: *const T}"][shape="record"];
+    ndLeaf[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nLeaf}"][shape="record"];
+    ndNode[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nNode}"][shape="record"];
+    ndConfig[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nConfig}"][shape="record"];
+    ndParent[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nParent}"][shape="record"];
+    ndHandle[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nHandle}"][shape="record"];
+    ndTree[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nTree&lt;\'a&gt;|- inline: Leaf\n- boxed: Box&lt;Leaf&gt;\n- borrowed: &amp;\'a Node\n- children: Vec&lt;Rc&lt;Node&gt;&gt;\n- config: Arc&lt;Config&gt;\n- parent: Weak&lt;Parent&gt;\n- handle: Gc&lt;Handle&gt;}"][shape="record"];
+    ndGc -> ndTree[label=""][arrowhead="diamond"][taillabel=<handle 1>];
+    ndLeaf -> ndTree[label=""][arrowhead="diamond"][taillabel=<inline 1<br/>boxed 1>];
+    ndNode -> ndTree[label=""][arrowhead="odiamond"][taillabel=<borrowed 1<br/>children 0..*>];
+    ndConfig -> ndTree[label=""][arrowhead="odiamond"][taillabel=<config 1>];
+    ndParent -> ndTree[label=""][arrowhead="vee"][taillabel=<parent 0..1>];
+    ndHandle -> ndTree[label=""][arrowhead="odiamond"][taillabel=<handle 1>];
+}
+"#);
+}
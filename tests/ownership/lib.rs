@@ -0,0 +1,31 @@
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+
+pub struct Gc<T> {
+    ptr: *const T,
+}
+
+pub struct Leaf {
+}
+
+pub struct Node {
+}
+
+pub struct Config {
+}
+
+pub struct Parent {
+}
+
+pub struct Handle {
+}
+
+pub struct Tree<'a> {
+    inline: Leaf,
+    boxed: Box<Leaf>,
+    borrowed: &'a Node,
+    children: Vec<Rc<Node>>,
+    config: Arc<Config>,
+    parent: Weak<Parent>,
+    handle: Gc<Handle>,
+}
@@ -0,0 +1,31 @@
+#![allow(dead_code, unused_variables)]
+extern crate mml;
+
+struct T {
+}
+
+struct U {
+}
+
+struct Cache<T> {
+    t: T,
+    items: Vec<T>,
+}
+
+impl<T> Cache<T> {
+    fn map<U>(&self, u: U) -> Option<U> {
+        None
+    }
+}
+
+#[test]
+fn test_parameter() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/parameter.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndT[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nT}"][shape="record"];
+    ndU[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nU}"][shape="record"];
+    ndCache[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nCache&lt;T&gt;|- t: T\n- items: Vec&lt;T&gt;|- map(&amp;self, u: U) -&gt; Option&lt;U&gt;}"][shape="record"];
+}
+"#);
+}
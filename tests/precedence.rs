@@ -0,0 +1,67 @@
+#![allow(dead_code, unused_variables)]
+extern crate mml;
+
+use std::rc::Rc;
+
+struct Foo {
+}
+
+struct Bar {
+    foo: Foo,
+}
+
+impl Bar {
+    fn set(&mut self, foo: Foo) {
+    }
+}
+
+struct Baz {
+    foo: Rc<Foo>,
+}
+
+impl Baz {
+    fn with(&self, foo: &Foo) {
+    }
+}
+
+struct Qux {
+}
+
+impl Qux {
+    fn swap(&self, foo: Foo) -> Foo {
+        foo
+    }
+}
+
+#[test]
+#[cfg(not(feature = "fn-emilgardis"))]
+fn test_precedence() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/precedence.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndFoo[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nFoo}"][shape="record"];
+    ndBar[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nBar|- foo: Foo|- set(&amp;mut self, foo: Foo)}"][shape="record"];
+    ndBaz[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nBaz|- foo: Rc&lt;Foo&gt;|- with(&amp;self, foo: &amp;Foo)}"][shape="record"];
+    ndQux[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nQux|- swap(&amp;self, foo: Foo) -&gt; Foo}"][shape="record"];
+    ndFoo -> ndBar[label=""][arrowhead="diamond"][taillabel=<foo 1>];
+    ndFoo -> ndBaz[label=""][arrowhead="odiamond"][taillabel=<foo 1>];
+    ndFoo -> ndQux[label=""][arrowhead="vee"][taillabel=<swap() 1>];
+}
+"#);
+}
+#[test]
+#[cfg(feature = "fn-emilgardis")]
+fn test_precedence() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/precedence.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndFoo[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nFoo}"][shape="record"];
+    ndBar[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nBar|- foo: Foo|- fn set(&amp;mut self, foo: Foo)}"][shape="record"];
+    ndBaz[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nBaz|- foo: Rc&lt;Foo&gt;|- fn with(&amp;self, foo: &amp;Foo)}"][shape="record"];
+    ndQux[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nQux|- fn swap(&amp;self, foo: Foo) -&gt; Foo}"][shape="record"];
+    ndFoo -> ndBar[label=""][arrowhead="diamond"][taillabel=<foo 1>];
+    ndFoo -> ndBaz[label=""][arrowhead="odiamond"][taillabel=<foo 1>];
+    ndFoo -> ndQux[label=""][arrowhead="vee"][taillabel=<swap() 1>];
+}
+"#);
+}
@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+extern crate mml;
+
+trait Shape {
+    fn area(&self) -> f64;
+
+    fn name(&self) -> String {
+        String::from("shape")
+    }
+}
+
+struct Square {
+    side: f64,
+}
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    fn name(&self) -> String {
+        String::from("square")
+    }
+}
+
+#[test]
+#[cfg(not(feature = "implem"))]
+fn test_provided() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/provided.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndShape[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nShape|area(&amp;Self) -&gt; f64 \{abstract\}\nname(&amp;Self) -&gt; String}"][shape="record"];
+    ndSquare[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nSquare|- side: f64}"][shape="record"];
+    ndShape -> ndSquare[label=""][style="dashed"][arrowhead="onormal"];
+}
+"#);
+}
+
+#[test]
+#[cfg(feature = "implem")]
+fn test_provided() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/provided.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndShape[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nShape|area(&amp;Self) -&gt; f64 \{abstract\}\nname(&amp;Self) -&gt; String||}"][shape="record"];
+    ndSquare[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nSquare|- side: f64||- area(&amp;Self) -&gt; f64\n- name(&amp;Self) -&gt; String \{redefines\}}"][shape="record"];
+    ndShape -> ndSquare[label=""][style="dashed"][arrowhead="onormal"];
+}
+"#);
+}
@@ -0,0 +1,23 @@
+extern crate mml;
+
+#[test]
+fn test_qualifier() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/qualifier/lib.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|&lt;&lt;&lt;const&gt;&gt;&gt; +&#818; &#818;n&#818;e&#818;w&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;A&#818;\n&lt;&lt;&lt;async&gt;&gt;&gt; + load(&amp;mut self)\n&lt;&lt;&lt;unsafe&gt;&gt;&gt; + raw(self: Box&lt;Self&gt;) -&gt; *const A\n- size(&amp;self) -&gt; usize}"][shape="record"];
+}
+"#);
+}
+
+#[test]
+fn test_qualifier_trait() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/qualifier/extend.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndFactory[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nFactory|n&#818;e&#818;w&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;S&#818;e&#818;l&#818;f&#818; \{abstract\}\n&lt;&lt;&lt;async&gt;&gt;&gt; load(&amp;mut Self) \{abstract\}\n&lt;&lt;&lt;unsafe&gt;&gt;&gt; raw(&amp;Self) -&gt; *const u8 \{abstract\}\nname(&amp;Self) -&gt; String}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nB}"][shape="record"];
+    ndFactory -> ndB[label=""][style="dashed"][arrowhead="onormal"];
+}
+"#);
+}
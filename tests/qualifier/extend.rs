@@ -0,0 +1,27 @@
+pub trait Factory {
+    fn new() -> Self;
+
+    async fn load(&mut self);
+
+    unsafe fn raw(&self) -> *const u8;
+
+    fn name(&self) -> String {
+        String::new()
+    }
+}
+
+pub struct B {
+}
+
+impl Factory for B {
+    fn new() -> Self {
+        B {}
+    }
+
+    async fn load(&mut self) {
+    }
+
+    unsafe fn raw(&self) -> *const u8 {
+        ::std::ptr::null()
+    }
+}
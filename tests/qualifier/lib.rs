@@ -0,0 +1,19 @@
+pub struct A {
+}
+
+impl A {
+    pub const fn new() -> A {
+        A {}
+    }
+
+    pub async fn load(&mut self) {
+    }
+
+    pub unsafe fn raw(self: Box<Self>) -> *const A {
+        Box::into_raw(self)
+    }
+
+    fn size(&self) -> usize {
+        0
+    }
+}
@@ -33,12 +33,27 @@ impl <T>B<T> {
 }
 
 #[test]
+#[cfg(not(feature = "derive-realization"))]
 fn test_realization() {
     assert_eq!(String::from_utf8(mml::rs2dot("tests/realization.rs").unwrap()).unwrap(),
         r#"digraph ml {
-    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|- a: T|- a(a: T) -&gt; Self}"][shape="record"];
-    ndB[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nB|a(&amp;Self) -&gt; Option&lt;T&gt;|- a(&amp;self) -&gt; Option&lt;T&gt;}"][shape="record"];
+    ndA[label="{&lt;&lt;&lt;derive(Debug)&gt;&gt;&gt;\n&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA&lt;T&gt;\nwhere T: Debug|- a: T|-&#818; &#818;a&#818;(&#818;a&#818;:&#818; &#818;T&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;S&#818;e&#818;l&#818;f&#818;}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nB&lt;T&gt;\nwhere T: Debug|a(&amp;Self) -&gt; Option&lt;T&gt; \{abstract\}|- a(&amp;self) -&gt; Option&lt;T&gt;}"][shape="record"];
     ndB -> ndA[label=""][style="dashed"][arrowhead="onormal"];
 }
 "#);
 }
+
+#[test]
+#[cfg(feature = "derive-realization")]
+fn test_realization() {
+    assert_eq!(String::from_utf8(mml::rs2dot("tests/realization.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA&lt;T&gt;\nwhere T: Debug|- a: T|-&#818; &#818;a&#818;(&#818;a&#818;:&#818; &#818;T&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;S&#818;e&#818;l&#818;f&#818;}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nB&lt;T&gt;\nwhere T: Debug|a(&amp;Self) -&gt; Option&lt;T&gt; \{abstract\}|- a(&amp;self) -&gt; Option&lt;T&gt;}"][shape="record"];
+    ndDebug[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nDebug}"][shape="record"];
+    ndB -> ndA[label=""][style="dashed"][arrowhead="onormal"];
+    ndDebug -> ndA[label=""][style="dashed"][arrowhead="onormal"];
+}
+"#);
+}
@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+extern crate mml;
+
+use std::rc::{Rc, Weak};
+
+struct Tree {
+    children: Vec<Tree>,
+}
+
+struct Node {
+    next: Option<Rc<Node>>,
+}
+
+struct Leaf {
+    parent: Option<Weak<Leaf>>,
+}
+
+struct Window {
+    owner: Weak<Self>,
+}
+
+struct Point {
+}
+
+impl Point {
+    fn origin() -> Point {
+        Point {
+        }
+    }
+}
+
+#[test]
+fn test_reflexive() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/reflexive.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndTree[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nTree|- children: Vec&lt;Tree&gt;}"][shape="record"];
+    ndNode[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nNode|- next: Option&lt;Rc&lt;Node&gt;&gt;}"][shape="record"];
+    ndLeaf[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nLeaf|- parent: Option&lt;Weak&lt;Leaf&gt;&gt;}"][shape="record"];
+    ndWindow[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nWindow|- owner: Weak&lt;Self&gt;}"][shape="record"];
+    ndPoint[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nPoint|-&#818; &#818;o&#818;r&#818;i&#818;g&#818;i&#818;n&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;P&#818;o&#818;i&#818;n&#818;t&#818;}"][shape="record"];
+    ndTree -> ndTree[label=""][arrowhead="diamond"][taillabel=<children 0..*>];
+    ndNode -> ndNode[label=""][arrowhead="odiamond"][taillabel=<next 0..1>];
+    ndLeaf -> ndLeaf[label=""][arrowhead="none"][taillabel=<parent 0..1>];
+    ndWindow -> ndWindow[label=""][arrowhead="none"][taillabel=<owner 0..1>];
+}
+"#);
+}
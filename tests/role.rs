@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+extern crate mml;
+
+use std::rc::Weak;
+
+struct Lexer {
+}
+
+impl Lexer {
+    fn tokens(&self) -> Vec<Token> {
+        Vec::new()
+    }
+}
+
+enum Token {
+    Word,
+}
+
+struct Parser {
+    lexer: Lexer,
+    lookahead: Option<Lexer>,
+}
+
+struct Cursor {
+    parser: Parser,
+    origin: Weak<Parser>,
+}
+
+#[test]
+fn test_role() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/role.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndLexer[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nLexer|- tokens(&amp;self) -&gt; Vec&lt;Token&gt;}"][shape="record"];
+    ndToken[label="{&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nToken|Word}"][shape="record"];
+    ndParser[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nParser|- lexer: Lexer\n- lookahead: Option&lt;Lexer&gt;}"][shape="record"];
+    ndCursor[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nCursor|- parser: Parser\n- origin: Weak&lt;Parser&gt;}"][shape="record"];
+    ndLexer -> ndParser[label=""][arrowhead="diamond"][taillabel=<lexer 1<br/>lookahead 0..1>];
+    ndToken -> ndLexer[label=""][arrowhead="vee"][taillabel=<tokens() 0..*>];
+    ndParser -> ndCursor[label=""][arrowhead="diamond"][taillabel=<parser 1<br/>origin 0..1>];
+}
+"#);
+}
@@ -0,0 +1,14 @@
+extern crate mml;
+
+#[test]
+fn test_syntax() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/syntax/lib.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndBuffer[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nBuffer&lt;const N: usize&gt;|- data: [u8; N]}"][shape="record"];
+    ndLending[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nLending|type Item&lt;\'a&gt;|next(&amp;mut Self) -&gt; Option&lt;Self::Item&lt;\'_&gt;&gt; \{abstract\}}"][shape="record"];
+    ndReader[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nReader|- source: Box&lt;dyn Read&gt;|&lt;&lt;&lt;async&gt;&gt;&gt; + fill(&amp;mut self, buffer: &amp;mut Buffer&lt;4&gt;) -&gt; Result&lt;usize, Error&gt;\n+ iter(&amp;self) -&gt; impl Iterator&lt;Item = u8&gt; + \'_}"][shape="record"];
+    ndBuffer -> ndReader[label=""][style="dashed"][arrowhead="vee"];
+}
+"#);
+}
@@ -0,0 +1,28 @@
+use std::io::{Error, Read};
+
+pub struct Buffer<const N: usize> {
+    data: [u8; N],
+}
+
+pub trait Lending {
+    type Item<'a> where Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+pub struct Reader {
+    source: Box<dyn Read>,
+}
+
+impl Reader {
+    pub async fn fill(&mut self, buffer: &mut Buffer<4>) -> Result<usize, Error> {
+        let Some(n) = self.peek() else { return Ok(0) };
+
+        self.source.read(&mut buffer.data)?;
+        Ok(n)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        std::iter::empty()
+    }
+}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+extern crate mml;
+
+trait Hash {
+}
+
+struct Cache<'a, K: Hash, const N: usize> {
+    keys: &'a [K; N],
+}
+
+enum Entry<V> where V: Hash + Clone {
+    Hit(V),
+    Miss,
+}
+
+#[test]
+fn test_template() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/template.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndHash[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nHash|}"][shape="record"];
+    ndCache[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nCache&lt;\'a, K: Hash, const N: usize&gt;|- keys: &amp;\'a [K; N]}"][shape="record"];
+    ndEntry[label="{&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nEntry&lt;V&gt;\nwhere V: Hash + Clone|Hit(V)\nMiss}"][shape="record"];
+    ndHash -> ndCache[label=""][style="dashed"][arrowhead="vee"];
+    ndHash -> ndEntry[label=""][style="dashed"][arrowhead="vee"];
+}
+"#);
+}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+extern crate mml;
+
+struct Meters(pub f64);
+
+struct Marker;
+
+struct Span(Meters, *const Marker);
+
+#[test]
+fn test_tuple() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/tuple.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndMeters[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nMeters|+ 0: f64}"][shape="record"];
+    ndMarker[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nMarker}"][shape="record"];
+    ndSpan[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nSpan|- 0: Meters\n- 1: *const Marker}"][shape="record"];
+    ndMeters -> ndSpan[label=""][arrowhead="diamond"][taillabel=<.0 1>];
+    ndMarker -> ndSpan[label=""][arrowhead="odiamond"][taillabel=<.1 1>];
+}
+"#);
+}
@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+extern crate mml;
+
+mod a {
+    pub struct Foo {
+    }
+}
+
+mod b {
+    pub struct Foo {
+    }
+}
+
+mod c {
+    use super::a::Foo;
+
+    impl Foo {
+        pub fn imported(&self) {
+        }
+    }
+}
+
+#[test]
+fn test_twin() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/twin.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndtwin_a_Foo[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nFoo|+ imported(&amp;self)}"][shape="record"];
+    ndtwin_b_Foo[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nFoo}"][shape="record"];
+}
+"#);
+}
@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+extern crate mml;
+
+struct Size {
+}
+
+enum Event {
+    Quit,
+    Key(char),
+    Resize { width: u32, size: Size },
+}
+
+#[test]
+fn test_variant() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/variant.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndSize[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nSize}"][shape="record"];
+    ndEvent[label="{&lt;&lt;&lt;Enumeration&gt;&gt;&gt;\nEvent|Quit\nKey(char)\nResize \{ width: u32, size: Size \}}"][shape="record"];
+    ndSize -> ndEvent[label=""][arrowhead="diamond"][taillabel=<Resize.size 1>];
+}
+"#);
+}
@@ -0,0 +1,59 @@
+extern crate mml;
+
+pub mod a {
+    pub struct A {
+        pub all: i32,
+        pub(crate) package: i32,
+        pub(super) parent: i32,
+        pub(in ::a) restricted: i32,
+        pub(self) module: i32,
+        private: i32,
+    }
+
+    impl A {
+        pub(crate) fn new() -> A {
+            A { all: 0, package: 0, parent: 0, restricted: 0, module: 0, private: 0 }
+        }
+    }
+
+    pub(crate) trait B {
+        fn b(&self);
+    }
+
+    impl B for A {
+        fn b(&self) {
+        }
+    }
+
+    impl Clone for A {
+        fn clone(&self) -> A {
+            A::new()
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "implem"))]
+fn test_visibility() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/visibility.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|+ all: i32\n~ package: i32\n# parent: i32\n# restricted: i32\n- module: i32\n- private: i32|~&#818; &#818;n&#818;e&#818;w&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;A&#818;}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nB|b(&amp;Self) \{abstract\}}"][shape="record"];
+    ndB -> ndA[label=""][style="dashed"][arrowhead="onormal"];
+}
+"#);
+}
+
+#[test]
+#[cfg(feature = "implem")]
+fn test_visibility() {
+    assert_eq!(
+        String::from_utf8(mml::rs2dot("tests/visibility.rs").unwrap()).unwrap(),
+        r#"digraph ml {
+    ndA[label="{&lt;&lt;&lt;Structure&gt;&gt;&gt;\nA|+ all: i32\n~ package: i32\n# parent: i32\n# restricted: i32\n- module: i32\n- private: i32|~&#818; &#818;n&#818;e&#818;w&#818;(&#818;)&#818; &#818;-&#818;&gt;&#818; &#818;A&#818;|~ b(&amp;Self)\n+ clone(&amp;Self) -&gt; A}"][shape="record"];
+    ndB[label="{&lt;&lt;&lt;Trait&gt;&gt;&gt;\nB|b(&amp;Self) \{abstract\}||}"][shape="record"];
+    ndB -> ndA[label=""][style="dashed"][arrowhead="onormal"];
+}
+"#);
+}